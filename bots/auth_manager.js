@@ -26,10 +26,12 @@ async function authorizeBot() {
     const adminKeypair = Keypair.fromSecretKey(bs58.decode(keyString));
     console.log(`👤 Admin Authority: ${adminKeypair.publicKey.toBase58()}`);
 
-    // 2. Derive Game State PDA
+    // 2. Derive Game State PDA (seeded by the game id as a little-endian u64)
     const programId = new PublicKey(process.env.PROGRAM_ID);
+    const gameId = Buffer.alloc(8);
+    gameId.writeBigUInt64LE(BigInt(process.env.GAME_ID || "1"));
     const [gameStatePda] = PublicKey.findProgramAddressSync(
-        [Buffer.from(process.env.GAME_STATE_SEED || "game_v4")],
+        [Buffer.from(process.env.GAME_STATE_SEED || "game_v4"), gameId],
         programId
     );
     console.log(`zk Game State PDA: ${gameStatePda.toBase58()}`);
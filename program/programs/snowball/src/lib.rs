@@ -1,71 +1,339 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_lang::Discriminator;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use spl_token_2022::extension::transfer_fee::TransferFeeAmount;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use anchor_spl::token_interface::{
+    self, Burn, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
 
 // UPDATE THIS ID BEFORE DEPLOY
 declare_id!("HFn2E5EV2MyUw42n2ZENx8btzeKBeQ9aDyo9GRQQ9ebs");
 
-const SNOW_DECIMALS: u64 = 1_000_000; 
-const SNOW_PUSH_COST: u64 = 1_000 * SNOW_DECIMALS;
-const ROUND_DURATION: i64 = 180;
 const GAME_SEED: &[u8] = b"game_v4";
+const CONFIG_SEED: &[u8] = b"config";
+const VAULT_SEED: &[u8] = b"snow_vault";
+const ROUND_SEED: &[u8] = b"round";
+const WINNINGS_SEED: &[u8] = b"winnings";
+const PLAYER_ROUND_SEED: &[u8] = b"player_round";
+const REFERRAL_SEED: &[u8] = b"referral";
+const PLAYER_STATS_SEED: &[u8] = b"player_stats";
+
+const BPS_DENOMINATOR: u64 = 10_000;
+// Fixed-point scale used to compound the exponential price curve.
+const PRICE_SCALE: u128 = 1_000_000_000_000;
+const MAX_KEEPER_BOUNTY_BPS: u16 = 500;
+// Longest any GameParams duration may be, so timestamp sums cannot overflow.
+const MAX_DURATION: i64 = 365 * 24 * 60 * 60;
+const MANAGER_ROTATION_DELAY: i64 = 24 * 60 * 60;
+const TREASURY_ROTATION_DELAY: i64 = 24 * 60 * 60;
+const MAX_BATCH_PUSHES: u64 = 50;
+/// Layout version of `GameState`. Every instruction refuses an account of
+/// another version; `migrate_state` upgrades the previous one.
+const GAME_STATE_VERSION: u8 = 2;
+/// Size of the GameState account of the original single-PDA program.
+const LEGACY_GAME_STATE_LEN: usize = 8 + 1 + 8 + 8 + 8 + 8 + (4 + 32 * 3) + 8 + 32 + 32;
+
+const PUMP_SELL_DISCRIMINATOR: [u8; 8] = [51, 230, 133, 164, 1, 127, 131, 173];
+const RAYDIUM_SWAP_BASE_IN: u8 = 9;
+
+mod pump_fun {
+    use super::*;
+    declare_id!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
+}
+
+mod raydium_v4 {
+    use super::*;
+    declare_id!("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8");
+}
 
 #[program]
 pub mod snowball {
     use super::*;
 
-    pub fn initialize_game(ctx: Context<InitializeGame>) -> Result<()> {
+    pub fn initialize_game(
+        ctx: Context<InitializeGame>,
+        game_id: u64,
+        season_id: u16,
+        liquidity_manager: Pubkey,
+        params: GameParams,
+    ) -> Result<()> {
+        params.validate()?;
         let game_state = &mut ctx.accounts.game_state;
         let clock = Clock::get()?;
+
+        let config = &mut ctx.accounts.config;
+        config.game = game_state.key();
+        config.params = params;
+        config.pending = None;
         
+        game_state.version = GAME_STATE_VERSION;
+        game_state.game_id = game_id;
+        game_state.season_id = season_id;
+        game_state.bump = ctx.bumps.game_state;
+        game_state.vault_bump = ctx.bumps.game_snow_vault;
+        game_state.snow_mint = ctx.accounts.snow_mint.key();
         game_state.is_active = true;
         game_state.round_number = 1;
-        game_state.timer_end_timestamp = clock.unix_timestamp + ROUND_DURATION;
+        game_state.round_start_timestamp = clock.unix_timestamp;
+        game_state.timer_end_timestamp = clock.unix_timestamp + params.round_duration;
+        game_state.round_deadline = params.round_deadline(clock.unix_timestamp);
         game_state.snow_collected = 0;
         game_state.pot_balance_sol = 0;
         game_state.push_count = 0;
         game_state.authority = ctx.accounts.authority.key();
-        game_state.liquidity_manager = ctx.accounts.authority.key(); 
+        game_state.liquidity_manager = liquidity_manager;
+        game_state.pending_authority = None;
+        game_state.pending_liquidity_manager = None;
+        game_state.guardian = ctx.accounts.authority.key();
+        game_state.treasury = ctx.accounts.authority.key();
+        game_state.pending_treasury = None;
         game_state.last_pushers = Vec::new();
         Ok(())
     }
 
     pub fn push_ball(ctx: Context<PushBall>) -> Result<()> {
-        let game_state = &mut ctx.accounts.game_state;
-        let clock = Clock::get()?;
+        push_snow(ctx, 1)
+    }
 
-        require!(game_state.is_active, GameError::GameNotActive);
+    /// Buys `count` pushes with a single SNOW transfer. The timer moves once
+    /// and the pusher takes one winner slot unless `repeat_winner_slots` is set.
+    pub fn push_many(ctx: Context<PushBall>, count: u64) -> Result<()> {
         require!(
-            clock.unix_timestamp < game_state.timer_end_timestamp,
-            GameError::RoundEnded
+            count > 0 && count <= MAX_BATCH_PUSHES,
+            GameError::InvalidPushCount
         );
+        push_snow(ctx, count)
+    }
 
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.user_snow_account.to_account_info(),
-            to: ctx.accounts.game_snow_vault.to_account_info(),
-            authority: ctx.accounts.user.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        
-        token::transfer(cpi_ctx, SNOW_PUSH_COST)?;
+    /// Returns the SNOW price of the next push in the current round.
+    pub fn quote_push(ctx: Context<QuotePush>) -> Result<u64> {
+        ctx.accounts
+            .config
+            .params
+            .push_price(ctx.accounts.game_state.push_count)
+    }
+
+    /// Pushes for a flat lamport price instead of SNOW. The lamports go
+    /// straight into the pot; there is no burn or referral share.
+    pub fn push_ball_sol(ctx: Context<PushBallSol>) -> Result<()> {
+        let params = &ctx.accounts.config.params;
+        let clock = Clock::get()?;
+        let price = params.sol_push_price;
+        require!(price > 0, GameError::SolPushDisabled);
+        ctx.accounts.game_state.require_push_open(clock.unix_timestamp)?;
+
+        record_player_push(
+            &ctx.accounts.game_state,
+            ctx.accounts.user.key(),
+            &mut ctx.accounts.winnings,
+            &mut ctx.accounts.player_round,
+            &mut ctx.accounts.player_stats,
+            [ctx.bumps.winnings, ctx.bumps.player_round, ctx.bumps.player_stats],
+            1,
+        )?;
+        let stats = &mut ctx.accounts.player_stats;
+        stats.sol_spent = stats.sol_spent.checked_add(price).ok_or(GameError::MathOverflow)?;
+
+        let ix = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.user.key(),
+            &ctx.accounts.game_state.key(),
+            price,
+        );
+        anchor_lang::solana_program::program::invoke(
+            &ix,
+            &[
+                ctx.accounts.user.to_account_info(),
+                ctx.accounts.game_state.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
 
-        game_state.snow_collected = game_state.snow_collected
-            .checked_add(SNOW_PUSH_COST)
+        let game_state = &mut ctx.accounts.game_state;
+        game_state.pot_balance_sol = game_state
+            .pot_balance_sol
+            .checked_add(price)
             .ok_or(GameError::MathOverflow)?;
-            
-        game_state.push_count += 1;
-        game_state.timer_end_timestamp = clock.unix_timestamp + ROUND_DURATION;
+        game_state.record_push(params, ctx.accounts.user.key(), 1, clock.unix_timestamp)?;
 
-        game_state.last_pushers.push(ctx.accounts.user.key());
-        if game_state.last_pushers.len() > 3 {
-            game_state.last_pushers.remove(0);
-        }
+        emit!(SolPushEvent {
+            game: game_state.key(),
+            pusher: ctx.accounts.user.key(),
+            round_number: game_state.round_number,
+            push_count: game_state.push_count,
+            amount: price,
+            pot_balance_sol: game_state.pot_balance_sol,
+            timer_end_timestamp: game_state.timer_end_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Stages new parameters; they take effect when the next round opens.
+    pub fn update_config(ctx: Context<UpdateConfig>, params: GameParams) -> Result<()> {
+        params.validate()?;
+        let config = &mut ctx.accounts.config;
+        config.pending = Some(params);
+
+        emit!(ConfigUpdateEvent {
+            game: config.game,
+            params,
+        });
+        Ok(())
+    }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        game_state.pending_authority = Some(new_authority);
+
+        emit!(AuthorityProposeEvent {
+            game: game_state.key(),
+            authority: game_state.authority,
+            proposed: new_authority,
+        });
+        Ok(())
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        let previous = game_state.authority;
+        game_state.authority = ctx.accounts.new_authority.key();
+        game_state.pending_authority = None;
+
+        emit!(AuthorityTransferEvent {
+            game: game_state.key(),
+            previous,
+            authority: game_state.authority,
+        });
         Ok(())
     }
 
+    /// Schedules a liquidity manager rotation. The new key has to accept it
+    /// once `MANAGER_ROTATION_DELAY` has passed.
     pub fn set_liquidity_manager(ctx: Context<SetLiquidityManager>, new_manager: Pubkey) -> Result<()> {
         let game_state = &mut ctx.accounts.game_state;
-        game_state.liquidity_manager = new_manager;
+        let clock = Clock::get()?;
+
+        game_state.pending_liquidity_manager = Some(new_manager);
+        game_state.liquidity_manager_eta = clock.unix_timestamp + MANAGER_ROTATION_DELAY;
+
+        emit!(LiquidityManagerProposeEvent {
+            game: game_state.key(),
+            liquidity_manager: game_state.liquidity_manager,
+            proposed: new_manager,
+            eta: game_state.liquidity_manager_eta,
+        });
+        Ok(())
+    }
+
+    pub fn accept_liquidity_manager(ctx: Context<AcceptLiquidityManager>) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        let clock = Clock::get()?;
+
+        require!(
+            clock.unix_timestamp >= game_state.liquidity_manager_eta,
+            GameError::TimelockActive
+        );
+
+        let previous = game_state.liquidity_manager;
+        game_state.liquidity_manager = ctx.accounts.new_manager.key();
+        game_state.pending_liquidity_manager = None;
+
+        emit!(LiquidityManagerTransferEvent {
+            game: game_state.key(),
+            previous,
+            liquidity_manager: game_state.liquidity_manager,
+        });
+        Ok(())
+    }
+
+    /// Schedules a treasury rotation. The new key has to accept it once
+    /// `TREASURY_ROTATION_DELAY` has passed.
+    pub fn set_treasury(ctx: Context<SetTreasury>, new_treasury: Pubkey) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        let clock = Clock::get()?;
+
+        game_state.pending_treasury = Some(new_treasury);
+        game_state.treasury_eta = clock.unix_timestamp + TREASURY_ROTATION_DELAY;
+
+        emit!(TreasuryProposeEvent {
+            game: game_state.key(),
+            treasury: game_state.treasury,
+            proposed: new_treasury,
+            eta: game_state.treasury_eta,
+        });
+        Ok(())
+    }
+
+    pub fn accept_treasury(ctx: Context<AcceptTreasury>) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        let clock = Clock::get()?;
+
+        require!(
+            clock.unix_timestamp >= game_state.treasury_eta,
+            GameError::TimelockActive
+        );
+
+        let previous = game_state.treasury;
+        game_state.treasury = ctx.accounts.new_treasury.key();
+        game_state.pending_treasury = None;
+
+        emit!(TreasuryTransferEvent {
+            game: game_state.key(),
+            previous,
+            treasury: game_state.treasury,
+        });
+        Ok(())
+    }
+
+    pub fn set_guardian(ctx: Context<SetGuardian>, new_guardian: Pubkey) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        game_state.guardian = new_guardian;
+
+        emit!(GuardianUpdateEvent {
+            game: game_state.key(),
+            guardian: new_guardian,
+        });
+        Ok(())
+    }
+
+    /// Freezes pushes, SOL deposits, SNOW withdrawals and round resolution.
+    pub fn pause_game(ctx: Context<PauseGame>) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        let clock = Clock::get()?;
+
+        game_state.is_paused = true;
+        game_state.paused_at = clock.unix_timestamp;
+
+        emit!(PauseEvent {
+            game: game_state.key(),
+            paused_by: ctx.accounts.signer.key(),
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Resumes play, pushing the round timer back by the time spent paused.
+    pub fn unpause_game(ctx: Context<UnpauseGame>) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        let clock = Clock::get()?;
+
+        let paused_for = clock.unix_timestamp - game_state.paused_at;
+        game_state.is_paused = false;
+        game_state.timer_end_timestamp += paused_for;
+        if game_state.round_deadline > 0 {
+            game_state.round_deadline += paused_for;
+        }
+
+        emit!(UnpauseEvent {
+            game: game_state.key(),
+            paused_for,
+            timer_end_timestamp: game_state.timer_end_timestamp,
+        });
         Ok(())
     }
 
@@ -86,36 +354,184 @@ pub mod snowball {
             ],
         )?;
 
-        game_state.pot_balance_sol += amount;
+        game_state.record_sol_deposit(amount)?;
+
+        emit!(SolDepositEvent {
+            game: game_state.key(),
+            depositor: ctx.accounts.authority.key(),
+            round_number: game_state.round_number,
+            amount,
+            pot_balance_sol: game_state.pot_balance_sol,
+        });
         Ok(())
     }
 
     pub fn withdraw_snow_for_swap(ctx: Context<WithdrawSnow>, amount: u64) -> Result<()> {
         let game_state = &mut ctx.accounts.game_state;
+        let clock = Clock::get()?;
         
         require!(
             ctx.accounts.authority.key() == game_state.liquidity_manager, 
             GameError::Unauthorized
         );
+        game_state.record_snow_withdrawal(
+            &ctx.accounts.config.params,
+            amount,
+            ctx.accounts.game_snow_vault.amount,
+            clock.unix_timestamp,
+        )?;
         
-        let seeds = &[GAME_SEED.as_ref(), &[ctx.bumps.game_state]];
+        let game_id = game_state.game_id.to_le_bytes();
+        let seeds = &[GAME_SEED, game_id.as_ref(), &[game_state.bump]];
         let signer = &[&seeds[..]];
 
-        let cpi_accounts = Transfer {
+        let cpi_accounts = TransferChecked {
             from: ctx.accounts.game_snow_vault.to_account_info(),
+            mint: ctx.accounts.snow_mint.to_account_info(),
             to: ctx.accounts.manager_snow_account.to_account_info(),
             authority: game_state.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
 
-        token::transfer(cpi_ctx, amount)?;
-        
-        game_state.snow_collected = game_state.snow_collected.saturating_sub(amount);
+        token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.snow_mint.decimals)?;
+
+        emit!(SnowWithdrawEvent {
+            game: game_state.key(),
+            manager: ctx.accounts.authority.key(),
+            destination: ctx.accounts.manager_snow_account.key(),
+            round_number: game_state.round_number,
+            amount,
+            snow_collected: game_state.snow_collected,
+            round_snow_withdrawn: game_state.round_snow_withdrawn,
+        });
+        Ok(())
+    }
+
+    /// Sells SNOW from the game vault on a DEX, with the game PDA as the
+    /// seller, and credits the SOL received to the pot. The venue's accounts
+    /// are passed as remaining accounts in the venue's own order, and
+    /// `min_sol_out` must be set.
+    pub fn convert_snow<'info>(
+        ctx: Context<'_, '_, '_, 'info, ConvertSnow<'info>>,
+        venue: SwapVenue,
+        amount_in: u64,
+        min_sol_out: u64,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let layout = venue.layout();
+        let swap_accounts = ctx.remaining_accounts;
+        let game_info = ctx.accounts.game_state.to_account_info();
+
+        require!(min_sol_out > 0, GameError::SlippageExceeded);
+        require_keys_eq!(
+            ctx.accounts.swap_program.key(),
+            layout.program_id,
+            GameError::InvalidSwapAccounts
+        );
+        // AMM v4 pools only hold SPL Token mints.
+        require!(
+            venue != SwapVenue::RaydiumV4 || ctx.accounts.token_program.key() == spl_token::ID,
+            GameError::InvalidSwapAccounts
+        );
+        require!(
+            swap_accounts.len() == layout.account_count,
+            GameError::InvalidSwapAccounts
+        );
+        require_keys_eq!(
+            swap_accounts[layout.seller].key(),
+            game_info.key(),
+            GameError::InvalidSwapAccounts
+        );
+        require_keys_eq!(
+            swap_accounts[layout.source].key(),
+            ctx.accounts.game_snow_vault.key(),
+            GameError::InvalidSwapAccounts
+        );
+        if let Some(index) = layout.wsol_destination {
+            let wsol = ctx
+                .accounts
+                .wsol_account
+                .as_ref()
+                .ok_or(GameError::InvalidSwapAccounts)?;
+            require_keys_eq!(swap_accounts[index].key(), wsol.key(), GameError::InvalidSwapAccounts);
+            require!(wsol.amount == 0, GameError::InvalidSwapAccounts);
+        }
+
+        let snow_before = ctx.accounts.game_snow_vault.amount;
+        let lamports_before = game_info.lamports();
+
+        let ix = Instruction {
+            program_id: layout.program_id,
+            accounts: swap_accounts
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: account.key(),
+                    is_signer: account.is_signer || account.key() == game_info.key(),
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: venue.instruction_data(amount_in, min_sol_out),
+        };
+        let game_id = ctx.accounts.game_state.game_id.to_le_bytes();
+        let seeds = &[GAME_SEED, game_id.as_ref(), &[ctx.accounts.game_state.bump]];
+        let signer = &[&seeds[..]];
+        invoke_signed(&ix, swap_accounts, signer)?;
+
+        ctx.accounts.game_snow_vault.reload()?;
+        let snow_spent = snow_before
+            .checked_sub(ctx.accounts.game_snow_vault.amount)
+            .ok_or(GameError::InvalidSwapAccounts)?;
+        require!(snow_spent <= amount_in, GameError::InvalidSwapAccounts);
+
+        let mut sol_received = game_info
+            .lamports()
+            .checked_sub(lamports_before)
+            .ok_or(GameError::InvalidSwapAccounts)?;
+
+        // Wrapped SOL is unwrapped into the game PDA by closing the account;
+        // its rent goes back to the liquidity manager that funded it.
+        if let (Some(_), Some(wsol)) = (layout.wsol_destination, ctx.accounts.wsol_account.as_mut()) {
+            wsol.reload()?;
+            let wrapped = wsol.amount;
+            let rent = wsol.to_account_info().lamports().saturating_sub(wrapped);
+            token_interface::close_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                CloseAccount {
+                    account: wsol.to_account_info(),
+                    destination: game_info.clone(),
+                    authority: game_info.clone(),
+                },
+                signer,
+            ))?;
+            transfer_from_game(&game_info, &ctx.accounts.authority.to_account_info(), rent)?;
+            sol_received = sol_received.checked_add(wrapped).ok_or(GameError::MathOverflow)?;
+        }
+        require!(sol_received >= min_sol_out, GameError::SlippageExceeded);
+
+        let game_state = &mut ctx.accounts.game_state;
+        game_state.record_snow_withdrawal(
+            &ctx.accounts.config.params,
+            snow_spent,
+            snow_before,
+            clock.unix_timestamp,
+        )?;
+        game_state.record_sol_deposit(sol_received)?;
+
+        emit!(SnowConvertEvent {
+            game: game_state.key(),
+            venue,
+            round_number: game_state.round_number,
+            snow_in: snow_spent,
+            sol_out: sol_received,
+            pot_balance_sol: game_state.pot_balance_sol,
+        });
         Ok(())
     }
 
-    pub fn resolve_round(ctx: Context<ResolveRound>) -> Result<()> {
+    /// Permissionless once the timer has expired. The caller earns the
+    /// keeper bounty and may open the next round in the same instruction.
+    pub fn resolve_round(ctx: Context<ResolveRound>, restart: bool) -> Result<()> {
         let game_state = &mut ctx.accounts.game_state;
         let clock = Clock::get()?;
 
@@ -125,117 +541,2429 @@ pub mod snowball {
             GameError::RoundStillActive
         );
 
+        let params = ctx.accounts.config.params;
         let total_pot = game_state.pot_balance_sol;
-        
-        if total_pot > 0 {
-             **game_state.to_account_info().try_borrow_mut_lamports()? -= total_pot;
-             **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += total_pot;
+        let game_state_key = game_state.key();
+        let game_info = game_state.to_account_info();
+        require!(
+            spendable_lamports(&game_info)? >= game_state.committed_lamports()?,
+            GameError::PotMismatch
+        );
+
+        let keeper_bounty = share_of(total_pot, params.keeper_bounty_bps)?;
+        transfer_from_game(&game_info, &ctx.accounts.keeper.to_account_info(), keeper_bounty)?;
+        let distributable = total_pot - keeper_bounty;
+        let winners = [
+            &mut ctx.accounts.winner_1,
+            &mut ctx.accounts.winner_2,
+            &mut ctx.accounts.winner_3,
+        ];
+        let winner_stats = [
+            &mut ctx.accounts.winner_1_stats,
+            &mut ctx.accounts.winner_2_stats,
+            &mut ctx.accounts.winner_3_stats,
+        ];
+        let ranked: Vec<Pubkey> = game_state.last_pushers.iter().rev().copied().collect();
+
+        // Only a pusher's highest slot takes an account. Anchor writes every
+        // account back on exit, so a second copy of a credited account in a
+        // later slot would overwrite the credit; unused slots must be empty.
+        let used: [bool; 3] = std::array::from_fn(|slot| {
+            ranked
+                .get(slot)
+                .is_some_and(|pusher| ranked.iter().position(|key| key == pusher) == Some(slot))
+        });
+        for slot in 0..3 {
+            require!(
+                used[slot] || (winners[slot].is_none() && winner_stats[slot].is_none()),
+                GameError::WinnerMismatch
+            );
         }
 
-        game_state.pot_balance_sol = 0;
+        // Winner shares are credited to Winnings accounts and stay in the game
+        // PDA until claimed. A player holding several slots is credited through
+        // the account passed for their highest slot. Shares of empty slots
+        // (fewer than three pushers) roll over into the next round's pot.
+        let mut paid: u64 = keeper_bounty;
+        let mut credited: u64 = 0;
+        let mut paid_winners = [Pubkey::default(); 3];
+        let mut winner_payouts = [0u64; 3];
+        for (rank, pusher) in ranked.iter().enumerate() {
+            let slot = ranked.iter().position(|key| key == pusher).unwrap_or(rank);
+            let winnings = winners[slot].as_mut().ok_or(GameError::WinnerMismatch)?;
+            require_keys_eq!(winnings.game, game_state_key, GameError::WinnerMismatch);
+            require_keys_eq!(winnings.player, *pusher, GameError::WinnerMismatch);
+
+            let share = share_of(distributable, params.winner_shares_bps[rank])?;
+            winnings.amount = winnings.amount.checked_add(share).ok_or(GameError::MathOverflow)?;
+            winnings.last_credited_at = clock.unix_timestamp;
+
+            let stats = winner_stats[slot].as_mut().ok_or(GameError::WinnerMismatch)?;
+            require_keys_eq!(stats.game, game_state_key, GameError::WinnerMismatch);
+            require_keys_eq!(stats.player, *pusher, GameError::WinnerMismatch);
+            stats.wins[rank] = stats.wins[rank].checked_add(1).ok_or(GameError::MathOverflow)?;
+            stats.total_sol_won = stats.total_sol_won.checked_add(share).ok_or(GameError::MathOverflow)?;
+            credited = credited.checked_add(share).ok_or(GameError::MathOverflow)?;
+            paid_winners[rank] = *pusher;
+            winner_payouts[rank] = share;
+        }
+        paid = paid.checked_add(credited).ok_or(GameError::MathOverflow)?;
+        game_state.unclaimed_winnings = game_state
+            .unclaimed_winnings
+            .checked_add(credited)
+            .ok_or(GameError::MathOverflow)?;
+
+        let creator_share = share_of(distributable, params.creator_share_bps)?;
+        transfer_from_game(&game_info, &ctx.accounts.creator.to_account_info(), creator_share)?;
+        paid = paid.checked_add(creator_share).ok_or(GameError::MathOverflow)?;
+
+        // The dividend slice is split evenly per push and claimed lazily
+        // through PlayerRound accounts; the rounding remainder rolls over.
+        let dividend_per_push = share_of(distributable, params.dividend_share_bps)?
+            .checked_div(game_state.push_count)
+            .unwrap_or(0);
+        let dividend_pool = dividend_per_push
+            .checked_mul(game_state.push_count)
+            .ok_or(GameError::MathOverflow)?;
+        paid = paid.checked_add(dividend_pool).ok_or(GameError::MathOverflow)?;
+        game_state.unclaimed_dividends = game_state
+            .unclaimed_dividends
+            .checked_add(dividend_pool)
+            .ok_or(GameError::MathOverflow)?;
+
+        // The carryover is held apart from the pot and seeds the next round.
+        let carryover = share_of(distributable, params.carryover_bps)?;
+        paid = paid.checked_add(carryover).ok_or(GameError::MathOverflow)?;
+        game_state.next_round_seed = game_state
+            .next_round_seed
+            .checked_add(carryover)
+            .ok_or(GameError::MathOverflow)?;
+
+        game_state.pot_balance_sol = total_pot.checked_sub(paid).ok_or(GameError::MathOverflow)?;
         game_state.is_active = false;
+
+        let record = &mut ctx.accounts.round_record;
+        record.game = game_state.key();
+        record.round_number = game_state.round_number;
+        record.winners = paid_winners;
+        record.winner_payouts = winner_payouts;
+        record.creator_payout = creator_share;
+        record.keeper = ctx.accounts.keeper.key();
+        record.keeper_bounty = keeper_bounty;
+        record.dividend_pool = dividend_pool;
+        record.dividend_per_push = dividend_per_push;
+        record.seed = game_state.round_seed;
+        record.carryover = carryover;
+        record.push_count = game_state.push_count;
+        record.snow_collected = game_state.snow_collected;
+        record.snow_withdrawn = game_state.round_snow_withdrawn;
+        record.sol_deposited = game_state.round_sol_deposited;
+        record.pot = total_pot;
+        record.started_at = game_state.round_start_timestamp;
+        record.ended_at = game_state.timer_end_timestamp;
+        record.resolved_at = clock.unix_timestamp;
+
+        emit!(RoundEndedEvent {
+            game: game_state.key(),
+            round_number: game_state.round_number,
+            push_count: game_state.push_count,
+            pot: total_pot,
+            winners: paid_winners,
+            winner_payouts,
+            creator: ctx.accounts.creator.key(),
+            creator_payout: creator_share,
+            keeper: ctx.accounts.keeper.key(),
+            keeper_bounty,
+            dividend_pool,
+            carryover,
+            rollover: game_state.pot_balance_sol,
+            timestamp: clock.unix_timestamp,
+        });
+
+        if restart {
+            start_next_round(game_state, &mut ctx.accounts.config, clock.unix_timestamp);
+        }
         Ok(())
     }
 
-    pub fn reset_game(ctx: Context<ResetGame>) -> Result<()> {
+    pub fn claim_winnings(ctx: Context<ClaimWinnings>) -> Result<()> {
         let game_state = &mut ctx.accounts.game_state;
-        let clock = Clock::get()?;
+        let winnings = &mut ctx.accounts.winnings;
+        let amount = winnings.amount;
+        require!(amount > 0, GameError::NothingToClaim);
 
-        game_state.is_active = true;
-        game_state.timer_end_timestamp = clock.unix_timestamp + ROUND_DURATION;
-        game_state.push_count = 0;
-        game_state.last_pushers = Vec::new();
+        transfer_from_game(
+            &game_state.to_account_info(),
+            &ctx.accounts.player.to_account_info(),
+            amount,
+        )?;
+        winnings.amount = 0;
+        game_state.unclaimed_winnings = game_state
+            .unclaimed_winnings
+            .checked_sub(amount)
+            .ok_or(GameError::MathOverflow)?;
+
+        emit!(WinningsClaimEvent {
+            game: game_state.key(),
+            player: winnings.player,
+            amount,
+        });
         Ok(())
     }
-}
 
-#[account]
-pub struct GameState {
-    pub is_active: bool,
-    pub round_number: u64,
-    pub timer_end_timestamp: i64,
-    pub snow_collected: u64,
-    pub pot_balance_sol: u64,
-    pub last_pushers: Vec<Pubkey>,
-    pub push_count: u64,
-    pub authority: Pubkey,
-    pub liquidity_manager: Pubkey,
-}
+    /// Pays a player's dividend for a resolved round and closes their
+    /// PlayerRound account.
+    pub fn claim_dividend(ctx: Context<ClaimDividend>, round_number: u64) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        let pushes = ctx.accounts.player_round.pushes;
+        let amount = ctx
+            .accounts
+            .round_record
+            .dividend_per_push
+            .checked_mul(pushes)
+            .ok_or(GameError::MathOverflow)?;
 
-#[derive(Accounts)]
-pub struct InitializeGame<'info> {
-    #[account(
-        init, 
-        payer = authority, 
-        space = 8 + 1 + 8 + 8 + 8 + 8 + (4 + 32 * 3) + 8 + 32 + 32,
-        seeds = [GAME_SEED], 
-        bump
-    )]
-    pub game_state: Account<'info, GameState>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+        transfer_from_game(
+            &game_state.to_account_info(),
+            &ctx.accounts.player.to_account_info(),
+            amount,
+        )?;
+        game_state.unclaimed_dividends = game_state
+            .unclaimed_dividends
+            .checked_sub(amount)
+            .ok_or(GameError::MathOverflow)?;
 
-#[derive(Accounts)]
-pub struct PushBall<'info> {
-    #[account(mut, seeds = [GAME_SEED], bump)]
-    pub game_state: Account<'info, GameState>,
-    #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(mut)]
-    pub user_snow_account: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub game_snow_vault: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
-}
+        emit!(DividendClaimEvent {
+            game: game_state.key(),
+            player: ctx.accounts.player.key(),
+            round_number,
+            pushes,
+            amount,
+        });
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct DepositSol<'info> {
-    #[account(mut, seeds = [GAME_SEED], bump)]
-    pub game_state: Account<'info, GameState>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    /// Pays out the SNOW a referrer has earned from referred pushes.
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        let referral = &mut ctx.accounts.referral;
+        let amount = referral.earned;
+        require!(amount > 0, GameError::NothingToClaim);
 
-#[derive(Accounts)]
-pub struct SetLiquidityManager<'info> {
-    #[account(mut, seeds = [GAME_SEED], bump)]
-    pub game_state: Account<'info, GameState>,
-    #[account(
-        mut, 
-        constraint = authority.key() == game_state.authority @ GameError::Unauthorized
-    )]
-    pub authority: Signer<'info>,
-}
+        let game_id = game_state.game_id.to_le_bytes();
+        let seeds = &[GAME_SEED, game_id.as_ref(), &[game_state.bump]];
+        let signer = &[&seeds[..]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.game_snow_vault.to_account_info(),
+                    mint: ctx.accounts.snow_mint.to_account_info(),
+                    to: ctx.accounts.referrer_snow_account.to_account_info(),
+                    authority: game_state.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+            ctx.accounts.snow_mint.decimals,
+        )?;
 
-#[derive(Accounts)]
-pub struct WithdrawSnow<'info> {
-    #[account(mut, seeds = [GAME_SEED], bump)]
-    pub game_state: Account<'info, GameState>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    #[account(mut)]
-    pub game_snow_vault: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub manager_snow_account: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
-}
+        referral.earned = 0;
+        game_state.unclaimed_referral_snow = game_state
+            .unclaimed_referral_snow
+            .checked_sub(amount)
+            .ok_or(GameError::MathOverflow)?;
 
-#[derive(Accounts)]
-pub struct ResolveRound<'info> {
-    #[account(mut, seeds = [GAME_SEED], bump)]
-    pub game_state: Account<'info, GameState>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+        emit!(ReferralClaimEvent {
+            game: game_state.key(),
+            referrer: referral.player,
+            amount,
+        });
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct ResetGame<'info> {
-    #[account(mut, seeds = [GAME_SEED], bump)]
+    /// Moves winnings left unclaimed past `claim_expiry` into the seed of
+    /// the next round.
+    pub fn sweep_expired_winnings(ctx: Context<SweepExpiredWinnings>) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        let winnings = &mut ctx.accounts.winnings;
+        let clock = Clock::get()?;
+
+        let amount = winnings.amount;
+        require!(amount > 0, GameError::NothingToClaim);
+        require!(
+            clock.unix_timestamp
+                >= winnings.last_credited_at + ctx.accounts.config.params.claim_expiry,
+            GameError::ClaimNotExpired
+        );
+
+        winnings.amount = 0;
+        game_state.unclaimed_winnings = game_state
+            .unclaimed_winnings
+            .checked_sub(amount)
+            .ok_or(GameError::MathOverflow)?;
+        game_state.next_round_seed = game_state
+            .next_round_seed
+            .checked_add(amount)
+            .ok_or(GameError::MathOverflow)?;
+
+        emit!(WinningsSweepEvent {
+            game: game_state.key(),
+            player: winnings.player,
+            amount,
+            next_round_seed: game_state.next_round_seed,
+        });
+        Ok(())
+    }
+
+    /// Moves a dividend left unclaimed past `claim_expiry` into the seed of
+    /// the next round and closes the player's PlayerRound account, refunding
+    /// its rent to them.
+    pub fn sweep_expired_dividend(ctx: Context<SweepExpiredDividend>, round_number: u64) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        let round_record = &ctx.accounts.round_record;
+        let clock = Clock::get()?;
+
+        let pushes = ctx.accounts.player_round.pushes;
+        let amount = round_record
+            .dividend_per_push
+            .checked_mul(pushes)
+            .ok_or(GameError::MathOverflow)?;
+        require!(amount > 0, GameError::NothingToClaim);
+        require!(
+            clock.unix_timestamp
+                >= round_record.resolved_at + ctx.accounts.config.params.claim_expiry,
+            GameError::ClaimNotExpired
+        );
+
+        game_state.unclaimed_dividends = game_state
+            .unclaimed_dividends
+            .checked_sub(amount)
+            .ok_or(GameError::MathOverflow)?;
+        game_state.next_round_seed = game_state
+            .next_round_seed
+            .checked_add(amount)
+            .ok_or(GameError::MathOverflow)?;
+
+        emit!(DividendSweepEvent {
+            game: game_state.key(),
+            player: ctx.accounts.player.key(),
+            round_number,
+            amount,
+            next_round_seed: game_state.next_round_seed,
+        });
+        Ok(())
+    }
+
+    /// Releases referral SNOW left unclaimed past `claim_expiry`. The SNOW
+    /// stays in the vault and is no longer held back from withdrawals.
+    pub fn sweep_expired_referral(ctx: Context<SweepExpiredReferral>) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        let referral = &mut ctx.accounts.referral;
+        let clock = Clock::get()?;
+
+        let amount = referral.earned;
+        require!(amount > 0, GameError::NothingToClaim);
+        require!(
+            clock.unix_timestamp
+                >= referral.last_credited_at + ctx.accounts.config.params.claim_expiry,
+            GameError::ClaimNotExpired
+        );
+
+        referral.earned = 0;
+        game_state.unclaimed_referral_snow = game_state
+            .unclaimed_referral_snow
+            .checked_sub(amount)
+            .ok_or(GameError::MathOverflow)?;
+
+        emit!(ReferralSweepEvent {
+            game: game_state.key(),
+            referrer: referral.player,
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Reconciles `pot_balance_sol` with the lamports actually held by the
+    /// game PDA. Any surplus (e.g. direct transfers) is added to the pot; a
+    /// deficit means the books are wrong and is reported as an error.
+    pub fn sync_pot(ctx: Context<SyncPot>) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        let spendable = spendable_lamports(&game_state.to_account_info())?;
+        let committed = game_state.committed_lamports()?;
+        require!(spendable >= committed, GameError::PotMismatch);
+
+        let surplus = spendable - committed;
+        game_state.pot_balance_sol = game_state
+            .pot_balance_sol
+            .checked_add(surplus)
+            .ok_or(GameError::MathOverflow)?;
+
+        emit!(PotSyncEvent {
+            game: game_state.key(),
+            spendable,
+            surplus,
+            pot_balance_sol: game_state.pot_balance_sol,
+        });
+        Ok(())
+    }
+
+    /// Upgrades a version 1 GameState, written before the treasury fields
+    /// existed, to the current layout. The account grows to the current
+    /// size, paid for by the authority, every old field is copied over and
+    /// the treasury starts out as the authority.
+    pub fn migrate_state(ctx: Context<MigrateState>, _game_id: u64) -> Result<()> {
+        let info = ctx.accounts.game_state.to_account_info();
+        let old = {
+            let data = info.try_borrow_data()?;
+            require!(
+                data.len() == 8 + GameStateV1::INIT_SPACE && data[..8] == GameState::DISCRIMINATOR,
+                GameError::InvalidLayout
+            );
+            GameStateV1::deserialize(&mut &data[8..])?
+        };
+        require!(old.version == 1, GameError::InvalidLayout);
+        require_keys_eq!(old.authority, ctx.accounts.authority.key(), GameError::Unauthorized);
+
+        let new_len = 8 + GameState::INIT_SPACE;
+        let top_up = Rent::get()?.minimum_balance(new_len).saturating_sub(info.lamports());
+        if top_up > 0 {
+            let ix = anchor_lang::solana_program::system_instruction::transfer(
+                &ctx.accounts.authority.key(),
+                &info.key(),
+                top_up,
+            );
+            anchor_lang::solana_program::program::invoke(
+                &ix,
+                &[
+                    ctx.accounts.authority.to_account_info(),
+                    info.clone(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+        }
+
+        let game_state = GameState {
+            version: GAME_STATE_VERSION,
+            game_id: old.game_id,
+            season_id: old.season_id,
+            bump: old.bump,
+            vault_bump: old.vault_bump,
+            snow_mint: old.snow_mint,
+            is_active: old.is_active,
+            round_number: old.round_number,
+            round_start_timestamp: old.round_start_timestamp,
+            timer_end_timestamp: old.timer_end_timestamp,
+            round_deadline: old.round_deadline,
+            snow_collected: old.snow_collected,
+            pot_balance_sol: old.pot_balance_sol,
+            unclaimed_winnings: old.unclaimed_winnings,
+            unclaimed_dividends: old.unclaimed_dividends,
+            next_round_seed: old.next_round_seed,
+            round_seed: old.round_seed,
+            last_pushers: old.last_pushers,
+            push_count: old.push_count,
+            authority: old.authority,
+            liquidity_manager: old.liquidity_manager,
+            pending_authority: old.pending_authority,
+            pending_liquidity_manager: old.pending_liquidity_manager,
+            liquidity_manager_eta: old.liquidity_manager_eta,
+            guardian: old.guardian,
+            is_paused: old.is_paused,
+            paused_at: old.paused_at,
+            round_snow_withdrawn: old.round_snow_withdrawn,
+            round_sol_deposited: old.round_sol_deposited,
+            withdraw_window_start: old.withdraw_window_start,
+            window_snow_withdrawn: old.window_snow_withdrawn,
+            total_snow_withdrawn: old.total_snow_withdrawn,
+            total_sol_deposited: old.total_sol_deposited,
+            total_burned: old.total_burned,
+            unclaimed_referral_snow: old.unclaimed_referral_snow,
+            treasury: old.authority,
+            pending_treasury: None,
+            treasury_eta: 0,
+        };
+        info.realloc(new_len, true)?;
+        game_state.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+        emit!(StateMigrateEvent {
+            game: info.key(),
+            version: GAME_STATE_VERSION,
+        });
+        Ok(())
+    }
+
+    /// Moves the SOL and SNOW left in a game PDA of an earlier seed scheme
+    /// (a single `legacy_seed`, such as the original `game_v4`) into this
+    /// game, then closes the legacy accounts. The legacy game must have the
+    /// same authority.
+    pub fn drain_legacy(ctx: Context<DrainLegacy>, legacy_seed: Vec<u8>) -> Result<()> {
+        let legacy_info = ctx.accounts.legacy_state.to_account_info();
+        let legacy = {
+            let data = legacy_info.try_borrow_data()?;
+            // A current game can sit at a legacy-looking seed (`game_v4` and
+            // its game id concatenate to the same bytes), so the size is what
+            // tells the layouts apart.
+            require!(
+                data.len() == LEGACY_GAME_STATE_LEN && data[..8] == GameState::DISCRIMINATOR,
+                GameError::InvalidLayout
+            );
+            LegacyGameState::deserialize(&mut &data[8..])?
+        };
+        require_keys_eq!(
+            legacy.authority,
+            ctx.accounts.authority.key(),
+            GameError::Unauthorized
+        );
+
+        let bump = [ctx.bumps.legacy_state];
+        let seeds = &[legacy_seed.as_slice(), &bump];
+        let signer = &[&seeds[..]];
+
+        let mut snow = 0;
+        if let Some(legacy_vault) = ctx.accounts.legacy_vault.as_ref() {
+            snow = legacy_vault.amount;
+            if snow > 0 {
+                token_interface::transfer_checked(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        TransferChecked {
+                            from: legacy_vault.to_account_info(),
+                            mint: ctx.accounts.snow_mint.to_account_info(),
+                            to: ctx.accounts.game_snow_vault.to_account_info(),
+                            authority: legacy_info.clone(),
+                        },
+                        signer,
+                    ),
+                    snow,
+                    ctx.accounts.snow_mint.decimals,
+                )?;
+            }
+            token_interface::close_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                CloseAccount {
+                    account: legacy_vault.to_account_info(),
+                    destination: ctx.accounts.authority.to_account_info(),
+                    authority: legacy_info.clone(),
+                },
+                signer,
+            ))?;
+        }
+
+        // Every lamport of the legacy PDA, its rent included, joins the pot;
+        // the emptied account is removed by the runtime.
+        let game_info = ctx.accounts.game_state.to_account_info();
+        let lamports = legacy_info.lamports();
+        **game_info.try_borrow_mut_lamports()? = game_info
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(GameError::MathOverflow)?;
+        **legacy_info.try_borrow_mut_lamports()? = 0;
+        legacy_info.try_borrow_mut_data()?.fill(0);
+
+        let game_state = &mut ctx.accounts.game_state;
+        game_state.pot_balance_sol = game_state
+            .pot_balance_sol
+            .checked_add(lamports)
+            .ok_or(GameError::MathOverflow)?;
+
+        emit!(LegacyDrainEvent {
+            game: game_state.key(),
+            legacy: legacy_info.key(),
+            lamports,
+            snow,
+            pot_balance_sol: game_state.pot_balance_sol,
+        });
+        Ok(())
+    }
+
+    /// Ends a season: sends the SNOW left in the vault and every lamport of
+    /// the game PDA above rent (pot and seed included) to the treasury,
+    /// then closes the vault, config and game accounts and returns their
+    /// rent to the authority. Every winner share, dividend and referral
+    /// reward must have been claimed or swept first.
+    pub fn close_game(ctx: Context<CloseGame>) -> Result<()> {
+        let game_state = &ctx.accounts.game_state;
+        require!(!game_state.is_active, GameError::RoundStillActive);
+        require!(
+            game_state.unclaimed_winnings == 0
+                && game_state.unclaimed_dividends == 0
+                && game_state.unclaimed_referral_snow == 0,
+            GameError::UnclaimedWinnings
+        );
+
+        let game_id = game_state.game_id.to_le_bytes();
+        let seeds = &[GAME_SEED, game_id.as_ref(), &[game_state.bump]];
+        let signer = &[&seeds[..]];
+
+        let snow = ctx.accounts.game_snow_vault.amount;
+        if snow > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.game_snow_vault.to_account_info(),
+                        mint: ctx.accounts.snow_mint.to_account_info(),
+                        to: ctx.accounts.treasury_snow_account.to_account_info(),
+                        authority: game_state.to_account_info(),
+                    },
+                    signer,
+                ),
+                snow,
+                ctx.accounts.snow_mint.decimals,
+            )?;
+        }
+
+        // Token-2022 refuses to close an account holding withheld transfer
+        // fees, so move them to the mint first. Harvesting needs no signer.
+        let vault_info = ctx.accounts.game_snow_vault.to_account_info();
+        if *vault_info.owner == spl_token_2022::ID {
+            let withheld = {
+                let data = vault_info.try_borrow_data()?;
+                StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?
+                    .get_extension::<TransferFeeAmount>()
+                    .map_or(0, |fees| u64::from(fees.withheld_amount))
+            };
+            if withheld > 0 {
+                let ix = spl_token_2022::extension::transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+                    &spl_token_2022::ID,
+                    &ctx.accounts.snow_mint.key(),
+                    &[&vault_info.key()],
+                )?;
+                anchor_lang::solana_program::program::invoke(
+                    &ix,
+                    &[ctx.accounts.snow_mint.to_account_info(), vault_info.clone()],
+                )?;
+            }
+        }
+
+        token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: vault_info,
+                destination: ctx.accounts.authority.to_account_info(),
+                authority: game_state.to_account_info(),
+            },
+            signer,
+        ))?;
+
+        let game_info = game_state.to_account_info();
+        let lamports = spendable_lamports(&game_info)?;
+        transfer_from_game(&game_info, &ctx.accounts.treasury.to_account_info(), lamports)?;
+
+        emit!(GameCloseEvent {
+            game: game_state.key(),
+            treasury: game_state.treasury,
+            snow,
+            lamports,
+        });
+        Ok(())
+    }
+
+    pub fn reset_game(ctx: Context<ResetGame>) -> Result<()> {
+        let game_state = &mut ctx.accounts.game_state;
+        let config = &mut ctx.accounts.config;
+        let clock = Clock::get()?;
+
+        // Every round must be resolved so that it leaves a RoundRecord.
+        require!(!game_state.is_active, GameError::RoundStillActive);
+
+        start_next_round(game_state, config, clock.unix_timestamp);
+        Ok(())
+    }
+}
+
+/// Opens the round after a resolved one, applying any staged config.
+fn start_next_round(
+    game_state: &mut Account<GameState>,
+    config: &mut Account<GameConfig>,
+    now: i64,
+) {
+    if let Some(pending) = config.pending.take() {
+        config.params = pending;
+    }
+
+    game_state.is_active = true;
+    game_state.round_number += 1;
+    game_state.round_start_timestamp = now;
+    game_state.timer_end_timestamp = now + config.params.round_duration;
+    game_state.round_deadline = config.params.round_deadline(now);
+    game_state.snow_collected = 0;
+    game_state.round_snow_withdrawn = 0;
+    game_state.round_sol_deposited = 0;
+    game_state.push_count = 0;
+    game_state.last_pushers = Vec::new();
+    game_state.round_seed = game_state.next_round_seed;
+    game_state.pot_balance_sol = game_state.pot_balance_sol.saturating_add(game_state.next_round_seed);
+    game_state.next_round_seed = 0;
+
+    emit!(GameResetEvent {
+        game: game_state.key(),
+        round_number: game_state.round_number,
+        timer_end_timestamp: game_state.timer_end_timestamp,
+        seed: game_state.round_seed,
+        pot_balance_sol: game_state.pot_balance_sol,
+    });
+}
+
+/// Charges `pushes` SNOW pushes to the caller: burns the configured share,
+/// moves the rest into the vault and credits the round.
+fn push_snow(ctx: Context<PushBall>, pushes: u64) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+    let params = &ctx.accounts.config.params;
+    let clock = Clock::get()?;
+    game_state.require_push_open(clock.unix_timestamp)?;
+
+    record_player_push(
+        game_state,
+        ctx.accounts.user.key(),
+        &mut ctx.accounts.winnings,
+        &mut ctx.accounts.player_round,
+        &mut ctx.accounts.player_stats,
+        [ctx.bumps.winnings, ctx.bumps.player_round, ctx.bumps.player_stats],
+        pushes,
+    )?;
+
+    // The referrer is fixed by the player's first push and must be a
+    // player of the same game. Only later pushes earn it a share.
+    let referral = &mut ctx.accounts.referral;
+    let first_push = referral.player == Pubkey::default();
+    if first_push {
+        referral.game = game_state.key();
+        referral.player = ctx.accounts.user.key();
+        referral.bump = ctx.bumps.referral;
+        if let Some(referrer) = ctx.accounts.referrer.as_ref() {
+            require_keys_eq!(referrer.game, game_state.key(), GameError::ReferrerMismatch);
+            require_keys_neq!(referrer.player, referral.player, GameError::ReferrerMismatch);
+            referral.referrer = Some(referrer.player);
+        }
+    }
+
+    let price = params.batch_price(game_state.push_count, pushes)?;
+    let stats = &mut ctx.accounts.player_stats;
+    stats.snow_spent = stats.snow_spent.checked_add(price).ok_or(GameError::MathOverflow)?;
+
+    let burned = share_of(price, params.burn_bps)?;
+    if burned > 0 {
+        token_interface::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.snow_mint.to_account_info(),
+                    from: ctx.accounts.user_snow_account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            burned,
+        )?;
+        game_state.total_burned = game_state
+            .total_burned
+            .checked_add(burned)
+            .ok_or(GameError::MathOverflow)?;
+    }
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.user_snow_account.to_account_info(),
+        mint: ctx.accounts.snow_mint.to_account_info(),
+        to: ctx.accounts.game_snow_vault.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    let vault_before = ctx.accounts.game_snow_vault.amount;
+    token_interface::transfer_checked(cpi_ctx, price - burned, ctx.accounts.snow_mint.decimals)?;
+
+    // Token-2022 transfer fees are withheld in the vault, so only the
+    // balance the vault actually gained counts towards the round.
+    ctx.accounts.game_snow_vault.reload()?;
+    let received = ctx
+        .accounts
+        .game_snow_vault
+        .amount
+        .checked_sub(vault_before)
+        .ok_or(GameError::MathOverflow)?;
+
+    // The referral share stays in the vault, owed to the referrer until
+    // claimed, and is kept out of the round's SNOW. Once a referrer is
+    // linked, every later push must pass its account.
+    let referral_share = match (ctx.accounts.referral.referrer, ctx.accounts.referrer.as_mut()) {
+        (Some(referrer_key), referrer) if !first_push => {
+            let referrer = referrer.ok_or(GameError::ReferrerMismatch)?;
+            require_keys_eq!(referrer.player, referrer_key, GameError::ReferrerMismatch);
+            require_keys_eq!(referrer.game, game_state.key(), GameError::ReferrerMismatch);
+
+            let share = share_of(received, params.referral_bps)?;
+            referrer.earned = referrer.earned.checked_add(share).ok_or(GameError::MathOverflow)?;
+            referrer.total_earned = referrer
+                .total_earned
+                .checked_add(share)
+                .ok_or(GameError::MathOverflow)?;
+            referrer.last_credited_at = clock.unix_timestamp;
+            share
+        }
+        _ => 0,
+    };
+    game_state.unclaimed_referral_snow = game_state
+        .unclaimed_referral_snow
+        .checked_add(referral_share)
+        .ok_or(GameError::MathOverflow)?;
+    game_state.snow_collected = game_state.snow_collected
+        .checked_add(received - referral_share)
+        .ok_or(GameError::MathOverflow)?;
+    game_state.record_push(params, ctx.accounts.user.key(), pushes, clock.unix_timestamp)?;
+
+    emit!(PushEvent {
+        game: game_state.key(),
+        pusher: ctx.accounts.user.key(),
+        round_number: game_state.round_number,
+        pushes,
+        push_count: game_state.push_count,
+        amount: price,
+        burned,
+        received,
+        referral_share,
+        snow_collected: game_state.snow_collected,
+        total_burned: game_state.total_burned,
+        timer_end_timestamp: game_state.timer_end_timestamp,
+    });
+    Ok(())
+}
+
+/// Opens the pusher's per-game accounts on their first push and counts the
+/// pushes towards them.
+fn record_player_push(
+    game_state: &Account<GameState>,
+    player: Pubkey,
+    winnings: &mut Account<Winnings>,
+    player_round: &mut Account<PlayerRound>,
+    stats: &mut Account<PlayerStats>,
+    bumps: [u8; 3],
+    pushes: u64,
+) -> Result<()> {
+    let [winnings_bump, player_round_bump, stats_bump] = bumps;
+
+    if winnings.player == Pubkey::default() {
+        winnings.game = game_state.key();
+        winnings.player = player;
+        winnings.bump = winnings_bump;
+    }
+
+    if player_round.player == Pubkey::default() {
+        player_round.game = game_state.key();
+        player_round.round_number = game_state.round_number;
+        player_round.player = player;
+        player_round.bump = player_round_bump;
+    }
+    player_round.pushes = player_round.pushes.checked_add(pushes).ok_or(GameError::MathOverflow)?;
+
+    if stats.player == Pubkey::default() {
+        stats.game = game_state.key();
+        stats.player = player;
+        stats.bump = stats_bump;
+    }
+    stats.total_pushes = stats.total_pushes.checked_add(pushes).ok_or(GameError::MathOverflow)?;
+    if player_round.pushes == pushes {
+        stats.rounds_played = stats.rounds_played.checked_add(1).ok_or(GameError::MathOverflow)?;
+    }
+    Ok(())
+}
+
+fn share_of(amount: u64, bps: u16) -> Result<u64> {
+    let share = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(GameError::MathOverflow)?
+        / BPS_DENOMINATOR as u128;
+    u64::try_from(share).map_err(|_| error!(GameError::MathOverflow))
+}
+
+/// Lamports held by the game PDA above its rent-exempt reserve.
+fn spendable_lamports(game: &AccountInfo) -> Result<u64> {
+    let reserve = Rent::get()?.minimum_balance(game.data_len());
+    Ok(game.lamports().saturating_sub(reserve))
+}
+
+/// Moves lamports out of the program-owned game PDA, never below its
+/// rent-exempt reserve.
+fn transfer_from_game(game: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    require!(spendable_lamports(game)? >= amount, GameError::PotMismatch);
+    let game_lamports = game.lamports().checked_sub(amount).ok_or(GameError::MathOverflow)?;
+    let to_lamports = to.lamports().checked_add(amount).ok_or(GameError::MathOverflow)?;
+    **game.try_borrow_mut_lamports()? = game_lamports;
+    **to.try_borrow_mut_lamports()? = to_lamports;
+    Ok(())
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct GameState {
+    pub version: u8,
+    pub game_id: u64,
+    pub season_id: u16,
+    pub bump: u8,
+    pub vault_bump: u8,
+    pub snow_mint: Pubkey,
+    pub is_active: bool,
+    pub round_number: u64,
+    pub round_start_timestamp: i64,
+    pub timer_end_timestamp: i64,
+    /// Hard end of the round from `max_round_length`; 0 when unlimited.
+    pub round_deadline: i64,
+    pub snow_collected: u64,
+    pub pot_balance_sol: u64,
+    /// Lamports credited to Winnings accounts but not yet claimed.
+    pub unclaimed_winnings: u64,
+    /// Lamports reserved for dividends of resolved rounds but not yet claimed.
+    pub unclaimed_dividends: u64,
+    /// Carryover set aside by `resolve_round`, moved into the pot when the
+    /// next round opens.
+    pub next_round_seed: u64,
+    /// Seed the current round opened with.
+    pub round_seed: u64,
+    #[max_len(3)]
+    pub last_pushers: Vec<Pubkey>,
+    pub push_count: u64,
+    pub authority: Pubkey,
+    pub liquidity_manager: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub pending_liquidity_manager: Option<Pubkey>,
+    /// Earliest time the pending liquidity manager can accept.
+    pub liquidity_manager_eta: i64,
+    /// May pause the game; only the authority can unpause it.
+    pub guardian: Pubkey,
+    pub is_paused: bool,
+    pub paused_at: i64,
+    pub round_snow_withdrawn: u64,
+    pub round_sol_deposited: u64,
+    pub withdraw_window_start: i64,
+    pub window_snow_withdrawn: u64,
+    pub total_snow_withdrawn: u64,
+    pub total_sol_deposited: u64,
+    /// SNOW burned by pushes over the lifetime of the game.
+    pub total_burned: u64,
+    /// Referral SNOW held in the vault until referrers claim it.
+    pub unclaimed_referral_snow: u64,
+    /// Receives the SNOW and SOL left over when the game is closed.
+    pub treasury: Pubkey,
+    pub pending_treasury: Option<Pubkey>,
+    /// Earliest time the pending treasury can accept.
+    pub treasury_eta: i64,
+}
+
+impl GameState {
+    /// Lamports the game PDA must hold on top of its rent-exempt reserve.
+    pub fn committed_lamports(&self) -> Result<u64> {
+        self.pot_balance_sol
+            .checked_add(self.unclaimed_winnings)
+            .and_then(|total| total.checked_add(self.unclaimed_dividends))
+            .and_then(|total| total.checked_add(self.next_round_seed))
+            .ok_or(error!(GameError::MathOverflow))
+    }
+
+    pub fn require_push_open(&self, now: i64) -> Result<()> {
+        require!(self.is_active, GameError::GameNotActive);
+        require!(
+            self.round_deadline == 0 || now < self.round_deadline,
+            GameError::RoundLengthExceeded
+        );
+        require!(now < self.timer_end_timestamp, GameError::RoundEnded);
+        Ok(())
+    }
+
+    /// Counts `pushes` pushes by `pusher`, then moves the timer and the
+    /// winner slots once.
+    pub fn record_push(
+        &mut self,
+        params: &GameParams,
+        pusher: Pubkey,
+        pushes: u64,
+        now: i64,
+    ) -> Result<()> {
+        self.push_count = self.push_count.checked_add(pushes).ok_or(GameError::MathOverflow)?;
+        self.timer_end_timestamp =
+            params.next_timer_end(now, self.timer_end_timestamp, self.round_deadline);
+
+        let slots = if params.repeat_winner_slots {
+            pushes.min(3)
+        } else {
+            self.last_pushers.retain(|key| *key != pusher);
+            1
+        };
+        for _ in 0..slots {
+            self.last_pushers.push(pusher);
+            if self.last_pushers.len() > 3 {
+                self.last_pushers.remove(0);
+            }
+        }
+        Ok(())
+    }
+
+    /// Enforces the liquidity manager's per-round and per-window caps and
+    /// the vault reserve, then books the withdrawal.
+    pub fn record_snow_withdrawal(
+        &mut self,
+        params: &GameParams,
+        amount: u64,
+        vault_balance: u64,
+        now: i64,
+    ) -> Result<()> {
+        if now >= self.withdraw_window_start + params.withdraw_window {
+            self.withdraw_window_start = now;
+            self.window_snow_withdrawn = 0;
+        }
+
+        let round_total = self
+            .round_snow_withdrawn
+            .checked_add(amount)
+            .ok_or(GameError::MathOverflow)?;
+        let window_total = self
+            .window_snow_withdrawn
+            .checked_add(amount)
+            .ok_or(GameError::MathOverflow)?;
+        require!(
+            round_total <= params.max_withdraw_per_round,
+            GameError::WithdrawLimitExceeded
+        );
+        require!(
+            window_total <= params.max_withdraw_per_window,
+            GameError::WithdrawLimitExceeded
+        );
+        require!(
+            vault_balance.saturating_sub(amount)
+                >= params.min_vault_reserve.saturating_add(self.unclaimed_referral_snow),
+            GameError::WithdrawLimitExceeded
+        );
+
+        self.round_snow_withdrawn = round_total;
+        self.window_snow_withdrawn = window_total;
+        self.total_snow_withdrawn = self
+            .total_snow_withdrawn
+            .checked_add(amount)
+            .ok_or(GameError::MathOverflow)?;
+        self.snow_collected = self.snow_collected.saturating_sub(amount);
+        Ok(())
+    }
+
+    pub fn record_sol_deposit(&mut self, amount: u64) -> Result<()> {
+        self.pot_balance_sol = self
+            .pot_balance_sol
+            .checked_add(amount)
+            .ok_or(GameError::MathOverflow)?;
+        self.round_sol_deposited = self
+            .round_sol_deposited
+            .checked_add(amount)
+            .ok_or(GameError::MathOverflow)?;
+        self.total_sol_deposited = self
+            .total_sol_deposited
+            .checked_add(amount)
+            .ok_or(GameError::MathOverflow)?;
+        Ok(())
+    }
+}
+
+/// GameState at layout version 1, before the treasury fields; read by
+/// `migrate_state`.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct GameStateV1 {
+    pub version: u8,
+    pub game_id: u64,
+    pub season_id: u16,
+    pub bump: u8,
+    pub vault_bump: u8,
+    pub snow_mint: Pubkey,
+    pub is_active: bool,
+    pub round_number: u64,
+    pub round_start_timestamp: i64,
+    pub timer_end_timestamp: i64,
+    pub round_deadline: i64,
+    pub snow_collected: u64,
+    pub pot_balance_sol: u64,
+    pub unclaimed_winnings: u64,
+    pub unclaimed_dividends: u64,
+    pub next_round_seed: u64,
+    pub round_seed: u64,
+    #[max_len(3)]
+    pub last_pushers: Vec<Pubkey>,
+    pub push_count: u64,
+    pub authority: Pubkey,
+    pub liquidity_manager: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub pending_liquidity_manager: Option<Pubkey>,
+    pub liquidity_manager_eta: i64,
+    pub guardian: Pubkey,
+    pub is_paused: bool,
+    pub paused_at: i64,
+    pub round_snow_withdrawn: u64,
+    pub round_sol_deposited: u64,
+    pub withdraw_window_start: i64,
+    pub window_snow_withdrawn: u64,
+    pub total_snow_withdrawn: u64,
+    pub total_sol_deposited: u64,
+    pub total_burned: u64,
+    pub unclaimed_referral_snow: u64,
+}
+
+/// GameState as written by the original single-PDA program, read by
+/// `drain_legacy`.
+#[derive(AnchorDeserialize)]
+pub struct LegacyGameState {
+    pub is_active: bool,
+    pub round_number: u64,
+    pub timer_end_timestamp: i64,
+    pub snow_collected: u64,
+    pub pot_balance_sol: u64,
+    pub last_pushers: Vec<Pubkey>,
+    pub push_count: u64,
+    pub authority: Pubkey,
+    pub liquidity_manager: Pubkey,
+}
+
+/// Per-player balance of credited but unclaimed winner shares.
+#[account]
+#[derive(InitSpace)]
+pub struct Winnings {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub bump: u8,
+    pub amount: u64,
+    pub last_credited_at: i64,
+}
+
+/// Lifetime totals of a player in one game, for on-chain leaderboards.
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerStats {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub bump: u8,
+    pub total_pushes: u64,
+    /// SNOW paid for pushes, including any burned part.
+    pub snow_spent: u64,
+    /// Lamports paid through `push_ball_sol`.
+    pub sol_spent: u64,
+    pub rounds_played: u64,
+    /// Rounds finished in each winner slot, last pusher first.
+    pub wins: [u64; 3],
+    /// Lamports credited as winner shares. Counts credits, not payouts:
+    /// shares later returned to the pot by `sweep_expired_winnings` stay
+    /// included.
+    pub total_sol_won: u64,
+}
+
+/// Per-player referral link, and the SNOW the player has earned as a
+/// referrer of others.
+#[account]
+#[derive(InitSpace)]
+pub struct Referral {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub bump: u8,
+    /// Set on the player's first push and never changed.
+    pub referrer: Option<Pubkey>,
+    pub earned: u64,
+    pub total_earned: u64,
+    /// Time of the last referral credit, for `sweep_expired_referral`.
+    pub last_credited_at: i64,
+}
+
+/// DEX used by `convert_snow` to sell SNOW for SOL.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SwapVenue {
+    /// Pump.fun bonding curve `sell`, paying native SOL to the seller.
+    PumpFun,
+    /// Raydium AMM v4 `swap_base_in`, paying wrapped SOL. SPL Token only.
+    RaydiumV4,
+}
+
+/// Where the game's own accounts sit in a venue's sell instruction.
+pub struct SwapLayout {
+    pub program_id: Pubkey,
+    pub account_count: usize,
+    pub seller: usize,
+    pub source: usize,
+    /// Wrapped SOL output account, for venues that do not pay native SOL.
+    pub wsol_destination: Option<usize>,
+}
+
+impl SwapVenue {
+    pub fn layout(&self) -> SwapLayout {
+        match self {
+            // Same accounts as liquidity-manager/src/pump_interface.rs:
+            // associated_user (5) is the source, user (6) the seller.
+            SwapVenue::PumpFun => SwapLayout {
+                program_id: pump_fun::ID,
+                account_count: 16,
+                seller: 6,
+                source: 5,
+                wsol_destination: None,
+            },
+            SwapVenue::RaydiumV4 => SwapLayout {
+                program_id: raydium_v4::ID,
+                account_count: 18,
+                seller: 17,
+                source: 15,
+                wsol_destination: Some(16),
+            },
+        }
+    }
+
+    pub fn instruction_data(&self, amount_in: u64, min_out: u64) -> Vec<u8> {
+        let mut data = Vec::with_capacity(8 + 16);
+        match self {
+            SwapVenue::PumpFun => data.extend_from_slice(&PUMP_SELL_DISCRIMINATOR),
+            SwapVenue::RaydiumV4 => data.push(RAYDIUM_SWAP_BASE_IN),
+        }
+        data.extend_from_slice(&amount_in.to_le_bytes());
+        data.extend_from_slice(&min_out.to_le_bytes());
+        data
+    }
+}
+
+/// A player's pushes in one round, used to pay the round's dividend.
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerRound {
+    pub game: Pubkey,
+    pub round_number: u64,
+    pub player: Pubkey,
+    pub bump: u8,
+    pub pushes: u64,
+}
+
+/// Immutable summary of a resolved round, written once by `resolve_round`.
+#[account]
+#[derive(InitSpace)]
+pub struct RoundRecord {
+    pub game: Pubkey,
+    pub round_number: u64,
+    pub winners: [Pubkey; 3],
+    pub winner_payouts: [u64; 3],
+    pub creator_payout: u64,
+    pub keeper: Pubkey,
+    pub keeper_bounty: u64,
+    pub dividend_pool: u64,
+    pub dividend_per_push: u64,
+    /// Carryover of the previous round this round opened with.
+    pub seed: u64,
+    /// Set aside from this round's pot to seed the next one.
+    pub carryover: u64,
+    pub push_count: u64,
+    pub snow_collected: u64,
+    pub snow_withdrawn: u64,
+    pub sol_deposited: u64,
+    pub pot: u64,
+    pub started_at: i64,
+    pub ended_at: i64,
+    pub resolved_at: i64,
+}
+
+/// How the SNOW price of a push grows with the round's `push_count`,
+/// starting from `GameParams::push_cost`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PriceCurve {
+    Fixed,
+    /// `push_cost + increment * push_count`.
+    Linear { increment: u64 },
+    /// `push_cost * (1 + growth_bps / 10_000) ^ push_count`.
+    Exponential { growth_bps: u16 },
+}
+
+/// Tunable game rules. Shares are in basis points and index 0 of
+/// `winner_shares_bps` is the last pusher. The keeper bounty is taken off
+/// the top of the pot before the shares are applied.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct GameParams {
+    pub push_cost: u64,
+    pub round_duration: i64,
+    pub winner_shares_bps: [u16; 3],
+    pub creator_share_bps: u16,
+    /// Slice of the pot split pro-rata across every push of the round.
+    pub dividend_share_bps: u16,
+    /// Slice of the pot held back to seed the next round.
+    pub carryover_bps: u16,
+    pub keeper_bounty_bps: u16,
+    /// Seconds after which unclaimed winnings may be swept back to the pot.
+    pub claim_expiry: i64,
+    /// Limits on `withdraw_snow_for_swap`, in SNOW base units.
+    pub max_withdraw_per_round: u64,
+    pub withdraw_window: i64,
+    pub max_withdraw_per_window: u64,
+    pub min_vault_reserve: u64,
+    pub price_curve: PriceCurve,
+    /// Slice of each push burned instead of sent to the vault.
+    pub burn_bps: u16,
+    /// Slice of each referred push owed to the player's referrer.
+    pub referral_bps: u16,
+    /// Lamport price of `push_ball_sol`; zero disables SOL pushes.
+    pub sol_push_price: u64,
+    /// Lets one wallet hold several winner slots, one per push, including
+    /// from a single `push_many`. Otherwise a push moves the pusher to the
+    /// last slot.
+    pub repeat_winner_slots: bool,
+    /// Seconds a push adds to the remaining time, up to `timer_cap`.
+    /// Zero keeps the hard reset to `round_duration`.
+    pub timer_extension: i64,
+    pub timer_cap: i64,
+    /// A push with less than `snipe_window` seconds left leaves at least
+    /// `snipe_min_remaining` seconds on the clock.
+    pub snipe_window: i64,
+    pub snipe_min_remaining: i64,
+    /// Pushes are rejected this many seconds after the round opened;
+    /// zero means no limit.
+    pub max_round_length: i64,
+}
+
+impl GameParams {
+    pub fn round_deadline(&self, round_start: i64) -> i64 {
+        if self.max_round_length > 0 {
+            round_start + self.max_round_length
+        } else {
+            0
+        }
+    }
+
+    /// Timer end after a push at `now`. A push never shortens the timer and
+    /// never moves it past the round deadline.
+    pub fn next_timer_end(&self, now: i64, timer_end: i64, deadline: i64) -> i64 {
+        let remaining = (timer_end - now).max(0);
+        let mut next_remaining = if self.timer_extension > 0 {
+            (remaining + self.timer_extension).min(self.timer_cap)
+        } else {
+            self.round_duration
+        };
+        if remaining < self.snipe_window {
+            next_remaining = next_remaining.max(self.snipe_min_remaining);
+        }
+
+        let next_end = (now + next_remaining).max(timer_end);
+        if deadline > 0 {
+            next_end.min(deadline)
+        } else {
+            next_end
+        }
+    }
+
+    /// Total SNOW price of `pushes` consecutive pushes from `push_count` on.
+    pub fn batch_price(&self, push_count: u64, pushes: u64) -> Result<u64> {
+        (push_count..push_count + pushes).try_fold(0u64, |total, n| {
+            total
+                .checked_add(self.push_price(n)?)
+                .ok_or(error!(GameError::MathOverflow))
+        })
+    }
+
+    /// SNOW price of a push made after `push_count` pushes this round.
+    pub fn push_price(&self, push_count: u64) -> Result<u64> {
+        let price = match self.price_curve {
+            PriceCurve::Fixed => u128::from(self.push_cost),
+            PriceCurve::Linear { increment } => u128::from(increment)
+                .checked_mul(u128::from(push_count))
+                .and_then(|step| step.checked_add(u128::from(self.push_cost)))
+                .ok_or(GameError::MathOverflow)?,
+            PriceCurve::Exponential { growth_bps } => {
+                let mut factor = PRICE_SCALE;
+                let mut base = PRICE_SCALE * u128::from(BPS_DENOMINATOR + u64::from(growth_bps))
+                    / u128::from(BPS_DENOMINATOR);
+                let mut exponent = push_count;
+                while exponent > 0 {
+                    if exponent & 1 == 1 {
+                        factor = factor.checked_mul(base).ok_or(GameError::MathOverflow)?
+                            / PRICE_SCALE;
+                    }
+                    exponent >>= 1;
+                    if exponent > 0 {
+                        base = base.checked_mul(base).ok_or(GameError::MathOverflow)? / PRICE_SCALE;
+                    }
+                }
+                u128::from(self.push_cost)
+                    .checked_mul(factor)
+                    .ok_or(GameError::MathOverflow)?
+                    / PRICE_SCALE
+            }
+        };
+        u64::try_from(price).map_err(|_| error!(GameError::MathOverflow))
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(self.push_cost > 0, GameError::InvalidConfig);
+        require!(self.round_duration > 0, GameError::InvalidConfig);
+        require!(self.keeper_bounty_bps <= MAX_KEEPER_BOUNTY_BPS, GameError::InvalidConfig);
+        require!(self.claim_expiry > 0, GameError::InvalidConfig);
+        require!(self.withdraw_window > 0, GameError::InvalidConfig);
+        require!(u64::from(self.burn_bps) <= BPS_DENOMINATOR, GameError::InvalidConfig);
+        require!(u64::from(self.referral_bps) <= BPS_DENOMINATOR, GameError::InvalidConfig);
+        require!(
+            [
+                self.round_duration,
+                self.claim_expiry,
+                self.withdraw_window,
+                self.timer_extension,
+                self.timer_cap,
+                self.snipe_window,
+                self.snipe_min_remaining,
+                self.max_round_length,
+            ]
+            .iter()
+            .all(|duration| (0..=MAX_DURATION).contains(duration)),
+            GameError::InvalidConfig
+        );
+        require!(
+            self.timer_extension == 0 || self.timer_cap >= self.timer_extension,
+            GameError::InvalidConfig
+        );
+        require!(
+            self.max_round_length == 0 || self.max_round_length >= self.round_duration,
+            GameError::InvalidConfig
+        );
+
+        let total_bps = self
+            .winner_shares_bps
+            .iter()
+            .map(|bps| u64::from(*bps))
+            .sum::<u64>()
+            + u64::from(self.creator_share_bps)
+            + u64::from(self.dividend_share_bps)
+            + u64::from(self.carryover_bps);
+        require!(total_bps == BPS_DENOMINATOR, GameError::InvalidConfig);
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct GameConfig {
+    pub game: Pubkey,
+    pub params: GameParams,
+    /// Staged by `update_config`, applied by the next `reset_game`.
+    pub pending: Option<GameParams>,
+}
+
+#[event]
+pub struct PushEvent {
+    pub game: Pubkey,
+    pub pusher: Pubkey,
+    pub round_number: u64,
+    pub pushes: u64,
+    pub push_count: u64,
+    pub amount: u64,
+    pub burned: u64,
+    pub received: u64,
+    pub referral_share: u64,
+    pub snow_collected: u64,
+    pub total_burned: u64,
+    pub timer_end_timestamp: i64,
+}
+
+#[event]
+pub struct SolPushEvent {
+    pub game: Pubkey,
+    pub pusher: Pubkey,
+    pub round_number: u64,
+    pub push_count: u64,
+    pub amount: u64,
+    pub pot_balance_sol: u64,
+    pub timer_end_timestamp: i64,
+}
+
+#[event]
+pub struct SolDepositEvent {
+    pub game: Pubkey,
+    pub depositor: Pubkey,
+    pub round_number: u64,
+    pub amount: u64,
+    pub pot_balance_sol: u64,
+}
+
+#[event]
+pub struct SnowWithdrawEvent {
+    pub game: Pubkey,
+    pub manager: Pubkey,
+    pub destination: Pubkey,
+    pub round_number: u64,
+    pub amount: u64,
+    pub snow_collected: u64,
+    pub round_snow_withdrawn: u64,
+}
+
+/// `winners[i]` received `winner_payouts[i]`; empty slots are the default key.
+#[event]
+pub struct RoundEndedEvent {
+    pub game: Pubkey,
+    pub round_number: u64,
+    pub push_count: u64,
+    pub pot: u64,
+    pub winners: [Pubkey; 3],
+    pub winner_payouts: [u64; 3],
+    pub creator: Pubkey,
+    pub creator_payout: u64,
+    pub keeper: Pubkey,
+    pub keeper_bounty: u64,
+    pub dividend_pool: u64,
+    pub carryover: u64,
+    pub rollover: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigUpdateEvent {
+    pub game: Pubkey,
+    pub params: GameParams,
+}
+
+#[event]
+pub struct WinningsClaimEvent {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DividendClaimEvent {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub round_number: u64,
+    pub pushes: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ReferralClaimEvent {
+    pub game: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct WinningsSweepEvent {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+    pub next_round_seed: u64,
+}
+
+#[event]
+pub struct DividendSweepEvent {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub round_number: u64,
+    pub amount: u64,
+    pub next_round_seed: u64,
+}
+
+#[event]
+pub struct ReferralSweepEvent {
+    pub game: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PotSyncEvent {
+    pub game: Pubkey,
+    pub spendable: u64,
+    pub surplus: u64,
+    pub pot_balance_sol: u64,
+}
+
+#[event]
+pub struct AuthorityProposeEvent {
+    pub game: Pubkey,
+    pub authority: Pubkey,
+    pub proposed: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferEvent {
+    pub game: Pubkey,
+    pub previous: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct LiquidityManagerProposeEvent {
+    pub game: Pubkey,
+    pub liquidity_manager: Pubkey,
+    pub proposed: Pubkey,
+    pub eta: i64,
+}
+
+#[event]
+pub struct LiquidityManagerTransferEvent {
+    pub game: Pubkey,
+    pub previous: Pubkey,
+    pub liquidity_manager: Pubkey,
+}
+
+#[event]
+pub struct TreasuryProposeEvent {
+    pub game: Pubkey,
+    pub treasury: Pubkey,
+    pub proposed: Pubkey,
+    pub eta: i64,
+}
+
+#[event]
+pub struct TreasuryTransferEvent {
+    pub game: Pubkey,
+    pub previous: Pubkey,
+    pub treasury: Pubkey,
+}
+
+#[event]
+pub struct SnowConvertEvent {
+    pub game: Pubkey,
+    pub venue: SwapVenue,
+    pub round_number: u64,
+    pub snow_in: u64,
+    pub sol_out: u64,
+    pub pot_balance_sol: u64,
+}
+
+#[event]
+pub struct GuardianUpdateEvent {
+    pub game: Pubkey,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct PauseEvent {
+    pub game: Pubkey,
+    pub paused_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnpauseEvent {
+    pub game: Pubkey,
+    pub paused_for: i64,
+    pub timer_end_timestamp: i64,
+}
+
+#[event]
+pub struct StateMigrateEvent {
+    pub game: Pubkey,
+    pub version: u8,
+}
+
+#[event]
+pub struct LegacyDrainEvent {
+    pub game: Pubkey,
+    pub legacy: Pubkey,
+    pub lamports: u64,
+    pub snow: u64,
+    pub pot_balance_sol: u64,
+}
+
+#[event]
+pub struct GameCloseEvent {
+    pub game: Pubkey,
+    pub treasury: Pubkey,
+    pub snow: u64,
+    pub lamports: u64,
+}
+
+#[event]
+pub struct GameResetEvent {
+    pub game: Pubkey,
+    pub round_number: u64,
+    pub timer_end_timestamp: i64,
+    pub seed: u64,
+    pub pot_balance_sol: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64, season_id: u16)]
+pub struct InitializeGame<'info> {
+    #[account(
+        init, 
+        payer = authority, 
+        space = 8 + GameState::INIT_SPACE,
+        seeds = [GAME_SEED, game_id.to_le_bytes().as_ref()], 
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GameConfig::INIT_SPACE,
+        seeds = [CONFIG_SEED, game_state.key().as_ref()],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [VAULT_SEED, game_state.key().as_ref()],
+        bump,
+        token::mint = snow_mint,
+        token::authority = game_state
+    )]
+    pub game_snow_vault: InterfaceAccount<'info, TokenAccount>,
+    pub snow_mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: RoundRecord of the season's first round. It must not exist
+    /// yet, so a closed game can only reopen under a new `season_id`.
+    #[account(
+        seeds = [
+            ROUND_SEED,
+            game_state.key().as_ref(),
+            season_id.to_le_bytes().as_ref(),
+            1u64.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = first_round_record.data_is_empty() @ GameError::SeasonAlreadyPlayed
+    )]
+    pub first_round_record: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PushBall<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout,
+        constraint = !game_state.is_paused @ GameError::GamePaused
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(seeds = [CONFIG_SEED, game_state.key().as_ref()], bump)]
+    pub config: Account<'info, GameConfig>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = game_state.snow_mint,
+        token::authority = user
+    )]
+    pub user_snow_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, game_state.key().as_ref()],
+        bump = game_state.vault_bump,
+        token::mint = game_state.snow_mint,
+        token::authority = game_state
+    )]
+    pub game_snow_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, address = game_state.snow_mint)]
+    pub snow_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + Winnings::INIT_SPACE,
+        seeds = [WINNINGS_SEED, game_state.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub winnings: Account<'info, Winnings>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + PlayerRound::INIT_SPACE,
+        seeds = [
+            PLAYER_ROUND_SEED,
+            game_state.key().as_ref(),
+            game_state.season_id.to_le_bytes().as_ref(),
+            game_state.round_number.to_le_bytes().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub player_round: Account<'info, PlayerRound>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + Referral::INIT_SPACE,
+        seeds = [REFERRAL_SEED, game_state.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub referral: Account<'info, Referral>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + PlayerStats::INIT_SPACE,
+        seeds = [PLAYER_STATS_SEED, game_state.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+    /// The referrer's own Referral account. Sets the referrer on the first
+    /// push and is credited on every push after that. Optional for players
+    /// without a referrer; required once one is linked.
+    #[account(mut)]
+    pub referrer: Option<Account<'info, Referral>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PushBallSol<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout,
+        constraint = !game_state.is_paused @ GameError::GamePaused
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(seeds = [CONFIG_SEED, game_state.key().as_ref()], bump)]
+    pub config: Account<'info, GameConfig>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + Winnings::INIT_SPACE,
+        seeds = [WINNINGS_SEED, game_state.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub winnings: Account<'info, Winnings>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + PlayerRound::INIT_SPACE,
+        seeds = [
+            PLAYER_ROUND_SEED,
+            game_state.key().as_ref(),
+            game_state.season_id.to_le_bytes().as_ref(),
+            game_state.round_number.to_le_bytes().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub player_round: Account<'info, PlayerRound>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + PlayerStats::INIT_SPACE,
+        seeds = [PLAYER_STATS_SEED, game_state.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(
+        constraint = authority.key() == game_state.authority @ GameError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PauseGame<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout,
+        constraint = !game_state.is_paused @ GameError::GamePaused
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(
+        constraint = signer.key() == game_state.guardian
+            || signer.key() == game_state.authority @ GameError::Unauthorized
+    )]
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UnpauseGame<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout,
+        constraint = game_state.is_paused @ GameError::GameNotPaused
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(
+        constraint = authority.key() == game_state.authority @ GameError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DepositSol<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout,
+        constraint = !game_state.is_paused @ GameError::GamePaused
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct QuotePush<'info> {
+    #[account(
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(seeds = [CONFIG_SEED, game_state.key().as_ref()], bump)]
+    pub config: Account<'info, GameConfig>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(mut, seeds = [CONFIG_SEED, game_state.key().as_ref()], bump)]
+    pub config: Account<'info, GameConfig>,
+    #[account(
+        constraint = authority.key() == game_state.authority @ GameError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(
+        constraint = authority.key() == game_state.authority @ GameError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(
+        constraint = game_state.pending_authority == Some(new_authority.key())
+            @ GameError::Unauthorized
+    )]
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetLiquidityManager<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(
+        mut, 
+        constraint = authority.key() == game_state.authority @ GameError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptLiquidityManager<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(
+        constraint = game_state.pending_liquidity_manager == Some(new_manager.key())
+            @ GameError::Unauthorized
+    )]
+    pub new_manager: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(
+        constraint = authority.key() == game_state.authority @ GameError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(
+        constraint = game_state.pending_treasury == Some(new_treasury.key())
+            @ GameError::Unauthorized
+    )]
+    pub new_treasury: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSnow<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout,
+        constraint = !game_state.is_paused @ GameError::GamePaused
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(seeds = [CONFIG_SEED, game_state.key().as_ref()], bump)]
+    pub config: Account<'info, GameConfig>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, game_state.key().as_ref()],
+        bump = game_state.vault_bump,
+        token::mint = game_state.snow_mint,
+        token::authority = game_state
+    )]
+    pub game_snow_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = game_state.snow_mint)]
+    pub snow_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = game_state.snow_mint,
+        token::authority = authority
+    )]
+    pub manager_snow_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ConvertSnow<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout,
+        constraint = !game_state.is_paused @ GameError::GamePaused
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(seeds = [CONFIG_SEED, game_state.key().as_ref()], bump)]
+    pub config: Account<'info, GameConfig>,
+    #[account(
+        mut,
+        constraint = authority.key() == game_state.liquidity_manager @ GameError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, game_state.key().as_ref()],
+        bump = game_state.vault_bump,
+        token::mint = game_state.snow_mint,
+        token::authority = game_state
+    )]
+    pub game_snow_vault: InterfaceAccount<'info, TokenAccount>,
+    /// Required by venues that pay out wrapped SOL; closed after the swap.
+    #[account(
+        mut,
+        token::mint = spl_token::native_mint::ID,
+        token::authority = game_state
+    )]
+    pub wsol_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: checked against the program id of the requested venue.
+    pub swap_program: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveRound<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout,
+        constraint = !game_state.is_paused @ GameError::GamePaused
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(mut, seeds = [CONFIG_SEED, game_state.key().as_ref()], bump)]
+    pub config: Account<'info, GameConfig>,
+    #[account(
+        init,
+        payer = keeper,
+        space = 8 + RoundRecord::INIT_SPACE,
+        seeds = [
+            ROUND_SEED,
+            game_state.key().as_ref(),
+            game_state.season_id.to_le_bytes().as_ref(),
+            game_state.round_number.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub round_record: Account<'info, RoundRecord>,
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    /// CHECK: receives the creator share; must be the game authority.
+    #[account(
+        mut,
+        constraint = creator.key() == game_state.authority @ GameError::Unauthorized
+    )]
+    pub creator: UncheckedAccount<'info>,
+    /// Winnings of the last entry of `last_pushers`.
+    #[account(mut)]
+    pub winner_1: Option<Account<'info, Winnings>>,
+    /// Winnings of the second-to-last entry of `last_pushers`. Must be
+    /// `None` when there is no such entry or it repeats a higher slot.
+    #[account(mut)]
+    pub winner_2: Option<Account<'info, Winnings>>,
+    /// Winnings of the third-to-last entry of `last_pushers`, with the same
+    /// rule as `winner_2`.
+    #[account(mut)]
+    pub winner_3: Option<Account<'info, Winnings>>,
+    /// PlayerStats of the same players, in the same order and with the same
+    /// empty slots.
+    #[account(mut)]
+    pub winner_1_stats: Option<Account<'info, PlayerStats>>,
+    #[account(mut)]
+    pub winner_2_stats: Option<Account<'info, PlayerStats>>,
+    #[account(mut)]
+    pub winner_3_stats: Option<Account<'info, PlayerStats>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimWinnings<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(
+        mut,
+        seeds = [WINNINGS_SEED, game_state.key().as_ref(), player.key().as_ref()],
+        bump = winnings.bump
+    )]
+    pub winnings: Account<'info, Winnings>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, game_state.key().as_ref(), referrer.key().as_ref()],
+        bump = referral.bump
+    )]
+    pub referral: Account<'info, Referral>,
+    pub referrer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, game_state.key().as_ref()],
+        bump = game_state.vault_bump,
+        token::mint = game_state.snow_mint,
+        token::authority = game_state
+    )]
+    pub game_snow_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = game_state.snow_mint)]
+    pub snow_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = game_state.snow_mint,
+        token::authority = referrer
+    )]
+    pub referrer_snow_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(round_number: u64)]
+pub struct ClaimDividend<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(
+        seeds = [
+            ROUND_SEED,
+            game_state.key().as_ref(),
+            game_state.season_id.to_le_bytes().as_ref(),
+            round_number.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub round_record: Account<'info, RoundRecord>,
+    #[account(
+        mut,
+        close = player,
+        seeds = [
+            PLAYER_ROUND_SEED,
+            game_state.key().as_ref(),
+            game_state.season_id.to_le_bytes().as_ref(),
+            round_number.to_le_bytes().as_ref(),
+            player.key().as_ref()
+        ],
+        bump = player_round.bump
+    )]
+    pub player_round: Account<'info, PlayerRound>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SweepExpiredWinnings<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(seeds = [CONFIG_SEED, game_state.key().as_ref()], bump)]
+    pub config: Account<'info, GameConfig>,
+    #[account(
+        mut,
+        seeds = [WINNINGS_SEED, game_state.key().as_ref(), winnings.player.as_ref()],
+        bump = winnings.bump
+    )]
+    pub winnings: Account<'info, Winnings>,
+    #[account(
+        constraint = authority.key() == game_state.authority @ GameError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(round_number: u64)]
+pub struct SweepExpiredDividend<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(seeds = [CONFIG_SEED, game_state.key().as_ref()], bump)]
+    pub config: Account<'info, GameConfig>,
+    #[account(
+        seeds = [
+            ROUND_SEED,
+            game_state.key().as_ref(),
+            game_state.season_id.to_le_bytes().as_ref(),
+            round_number.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub round_record: Account<'info, RoundRecord>,
+    #[account(
+        mut,
+        close = player,
+        seeds = [
+            PLAYER_ROUND_SEED,
+            game_state.key().as_ref(),
+            game_state.season_id.to_le_bytes().as_ref(),
+            round_number.to_le_bytes().as_ref(),
+            player.key().as_ref()
+        ],
+        bump = player_round.bump
+    )]
+    pub player_round: Account<'info, PlayerRound>,
+    /// CHECK: owner of `player_round`, refunded its rent.
+    #[account(mut, address = player_round.player)]
+    pub player: UncheckedAccount<'info>,
+    #[account(
+        constraint = authority.key() == game_state.authority @ GameError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SweepExpiredReferral<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(seeds = [CONFIG_SEED, game_state.key().as_ref()], bump)]
+    pub config: Account<'info, GameConfig>,
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, game_state.key().as_ref(), referral.player.as_ref()],
+        bump = referral.bump
+    )]
+    pub referral: Account<'info, Referral>,
+    #[account(
+        constraint = authority.key() == game_state.authority @ GameError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SyncPot<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+}
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct MigrateState<'info> {
+    /// CHECK: a version 1 GameState, checked and rewritten by the handler.
+    #[account(
+        mut,
+        owner = ID,
+        seeds = [GAME_SEED, game_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub game_state: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(legacy_seed: Vec<u8>)]
+pub struct DrainLegacy<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    /// CHECK: a legacy GameState, parsed by the handler.
+    #[account(
+        mut,
+        owner = ID,
+        seeds = [legacy_seed.as_ref()],
+        bump
+    )]
+    pub legacy_state: UncheckedAccount<'info>,
+    /// SNOW account owned by the legacy PDA; closed once emptied.
+    #[account(
+        mut,
+        token::mint = game_state.snow_mint,
+        token::authority = legacy_state
+    )]
+    pub legacy_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, game_state.key().as_ref()],
+        bump = game_state.vault_bump,
+        token::mint = game_state.snow_mint,
+        token::authority = game_state
+    )]
+    pub game_snow_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = game_state.snow_mint)]
+    pub snow_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        constraint = authority.key() == game_state.authority @ GameError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CloseGame<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout
+    )]
+    pub game_state: Account<'info, GameState>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [CONFIG_SEED, game_state.key().as_ref()],
+        bump
+    )]
+    pub config: Account<'info, GameConfig>,
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, game_state.key().as_ref()],
+        bump = game_state.vault_bump,
+        token::mint = game_state.snow_mint,
+        token::authority = game_state
+    )]
+    pub game_snow_vault: InterfaceAccount<'info, TokenAccount>,
+    /// Writable to take harvested Token-2022 transfer fees.
+    #[account(mut, address = game_state.snow_mint)]
+    pub snow_mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: the game's treasury; receives the lamports left in the game.
+    #[account(mut, address = game_state.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+    /// Receives the SNOW left in the vault.
+    #[account(
+        mut,
+        token::mint = game_state.snow_mint,
+        token::authority = game_state.treasury
+    )]
+    pub treasury_snow_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = authority.key() == game_state.authority @ GameError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ResetGame<'info> {
+    #[account(
+        mut,
+        seeds = [GAME_SEED, game_state.game_id.to_le_bytes().as_ref()],
+        bump = game_state.bump,
+        constraint = game_state.version == GAME_STATE_VERSION @ GameError::InvalidLayout,
+        constraint = !game_state.is_paused @ GameError::GamePaused
+    )]
     pub game_state: Account<'info, GameState>,
+    #[account(mut, seeds = [CONFIG_SEED, game_state.key().as_ref()], bump)]
+    pub config: Account<'info, GameConfig>,
     #[account(
         mut,
         constraint = authority.key() == game_state.authority @ GameError::Unauthorized
@@ -255,4 +2983,40 @@ pub enum GameError {
     RoundStillActive,
     #[msg("ErrAuth")]
     Unauthorized,
+    #[msg("ErrWinner")]
+    WinnerMismatch,
+    #[msg("ErrConfig")]
+    InvalidConfig,
+    #[msg("ErrNoClaim")]
+    NothingToClaim,
+    #[msg("ErrNotExpired")]
+    ClaimNotExpired,
+    #[msg("ErrPot")]
+    PotMismatch,
+    #[msg("ErrTimelock")]
+    TimelockActive,
+    #[msg("ErrWithdrawLimit")]
+    WithdrawLimitExceeded,
+    #[msg("ErrSwapAccounts")]
+    InvalidSwapAccounts,
+    #[msg("ErrSlippage")]
+    SlippageExceeded,
+    #[msg("ErrPaused")]
+    GamePaused,
+    #[msg("ErrNotPaused")]
+    GameNotPaused,
+    #[msg("ErrMaxLength")]
+    RoundLengthExceeded,
+    #[msg("ErrReferrer")]
+    ReferrerMismatch,
+    #[msg("ErrSolPush")]
+    SolPushDisabled,
+    #[msg("ErrPushCount")]
+    InvalidPushCount,
+    #[msg("ErrLayout")]
+    InvalidLayout,
+    #[msg("ErrUnclaimed")]
+    UnclaimedWinnings,
+    #[msg("ErrSeasonUsed")]
+    SeasonAlreadyPlayed,
 }
\ No newline at end of file
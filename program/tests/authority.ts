@@ -0,0 +1,117 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Env, Game, createTokenAccount, defaultParams, expectError, fundedPlayer, now, startEnv, warpTo } from "./helpers";
+
+describe("🔑 Snowball Effect: Authority and Liquidity Manager", () => {
+  const delay = 24 * 60 * 60;
+
+  let env: Env;
+  let game: Game;
+  let manager: anchor.web3.Keypair;
+  let proposedAt: number;
+
+  const acceptManager = (signer: anchor.web3.Keypair) =>
+    env.program.methods
+      .acceptLiquidityManager()
+      .accounts({ gameState: game.address, newManager: signer.publicKey })
+      .signers([signer])
+      .rpc();
+
+  const withdraw = async (signer: anchor.web3.Keypair) =>
+    env.program.methods
+      .withdrawSnowForSwap(new anchor.BN(1))
+      .accounts({
+        gameState: game.address,
+        config: game.config,
+        authority: signer.publicKey,
+        gameSnowVault: game.vault,
+        snowMint: game.mint.address,
+        managerSnowAccount: await createTokenAccount(env, game.mint, signer.publicKey),
+        tokenProgram: game.mint.tokenProgram,
+      })
+      .signers(signer === env.payer ? [] : [signer])
+      .rpc();
+
+  before(async () => {
+    env = await startEnv();
+    game = await Game.create(env, defaultParams());
+    manager = fundedPlayer(env.context, 1);
+    await game.push(await game.player());
+  });
+
+  it("Only lets the authority schedule a manager rotation", async () => {
+    const stranger = fundedPlayer(env.context, 1);
+
+    await expectError(
+      env.program.methods
+        .setLiquidityManager(stranger.publicKey)
+        .accounts({ gameState: game.address, authority: stranger.publicKey })
+        .signers([stranger])
+        .rpc(),
+      "Unauthorized"
+    );
+  });
+
+  it("Holds a manager rotation for a day", async () => {
+    await env.program.methods
+      .setLiquidityManager(manager.publicKey)
+      .accounts({ gameState: game.address, authority: env.payer.publicKey })
+      .rpc();
+    proposedAt = await now(env.context);
+
+    const state = await game.state();
+    assert.equal(state.liquidityManagerEta.toNumber(), proposedAt + delay);
+    await expectError(acceptManager(manager), "TimelockActive");
+
+    await warpTo(env.context, proposedAt + delay - 1);
+    await expectError(acceptManager(manager), "TimelockActive");
+  });
+
+  it("Only lets the proposed key accept the rotation", async () => {
+    await warpTo(env.context, proposedAt + delay);
+
+    await expectError(acceptManager(fundedPlayer(env.context, 1)), "Unauthorized");
+  });
+
+  it("Hands SNOW withdrawals to the new manager once accepted", async () => {
+    await acceptManager(manager);
+
+    const state = await game.state();
+    assert.equal(state.liquidityManager.toBase58(), manager.publicKey.toBase58());
+    assert.isNull(state.pendingLiquidityManager);
+    await expectError(withdraw(env.payer), "Unauthorized");
+    await withdraw(manager);
+  });
+
+  it("Transfers authority only when the proposed key accepts", async () => {
+    const next = fundedPlayer(env.context, 1);
+    await env.program.methods
+      .proposeAuthority(next.publicKey)
+      .accounts({ gameState: game.address, authority: env.payer.publicKey })
+      .rpc();
+
+    const stranger = fundedPlayer(env.context, 1);
+    await expectError(
+      env.program.methods
+        .acceptAuthority()
+        .accounts({ gameState: game.address, newAuthority: stranger.publicKey })
+        .signers([stranger])
+        .rpc(),
+      "Unauthorized"
+    );
+
+    await env.program.methods
+      .acceptAuthority()
+      .accounts({ gameState: game.address, newAuthority: next.publicKey })
+      .signers([next])
+      .rpc();
+    assert.equal((await game.state()).authority.toBase58(), next.publicKey.toBase58());
+    await expectError(
+      env.program.methods
+        .setLiquidityManager(env.payer.publicKey)
+        .accounts({ gameState: game.address, authority: env.payer.publicKey })
+        .rpc(),
+      "Unauthorized"
+    );
+  });
+});
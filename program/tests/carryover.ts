@@ -0,0 +1,57 @@
+import { assert } from "chai";
+import { Env, Game, defaultParams, nextSlot, startEnv } from "./helpers";
+
+describe("🌱 Snowball Effect: Round Seed", () => {
+  // 10% of each pot is held back to seed the next round.
+  const params = defaultParams({ creatorShareBps: 3_000, carryoverBps: 1_000 });
+  const pot = 10_000_000_000;
+  const carryover = ((pot - pot / 100) * 1_000) / 10_000;
+
+  let env: Env;
+  let game: Game;
+
+  before(async () => {
+    env = await startEnv();
+    game = await Game.create(env, params);
+    for (let i = 0; i < 3; i++) {
+      await game.push(await game.player());
+    }
+    await game.deposit(pot);
+    await game.expire();
+    await game.resolve();
+  });
+
+  it("Holds the carryover apart from the pot", async () => {
+    const state = await game.state();
+    assert.equal(state.nextRoundSeed.toNumber(), carryover);
+    assert.equal(state.potBalanceSol.toNumber(), 0);
+    assert.equal((await game.roundRecord(1)).carryover.toNumber(), carryover);
+
+    // The seed is owed, so a sync does not count it as surplus.
+    await nextSlot(env.context);
+    await env.program.methods.syncPot().accounts({ gameState: game.address }).rpc();
+    assert.equal((await game.state()).potBalanceSol.toNumber(), 0);
+  });
+
+  it("Adds the seed to the pot when the next round opens", async () => {
+    await env.program.methods
+      .resetGame()
+      .accounts({ gameState: game.address, config: game.config, authority: env.payer.publicKey })
+      .rpc();
+
+    const state = await game.state();
+    assert.equal(state.potBalanceSol.toNumber(), carryover);
+    assert.equal(state.roundSeed.toNumber(), carryover);
+    assert.equal(state.nextRoundSeed.toNumber(), 0);
+  });
+
+  it("Records the seed the round was played for", async () => {
+    await game.push(await game.player());
+    await game.expire();
+    await game.resolve();
+
+    const record = await game.roundRecord(2);
+    assert.equal(record.seed.toNumber(), carryover);
+    assert.equal(record.pot.toNumber(), carryover);
+  });
+});
@@ -0,0 +1,98 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Env,
+  Game,
+  createTokenAccount,
+  defaultParams,
+  expectError,
+  fundedPlayer,
+  lamports,
+  mintTo,
+  now,
+  startEnv,
+  tokenBalance,
+  warpTo,
+} from "./helpers";
+
+describe("🏁 Snowball Effect: Season Close", () => {
+  // 10% of each pot is held back to seed the next round.
+  const params = defaultParams({ creatorShareBps: 3_000, carryoverBps: 1_000 });
+  const pot = 10_000_000_000;
+
+  let env: Env;
+  let game: Game;
+  let treasury: anchor.web3.Keypair;
+  let treasurySnow: anchor.web3.PublicKey;
+
+  before(async () => {
+    env = await startEnv();
+    game = await Game.create(env, params);
+    treasury = fundedPlayer(env.context, 1);
+    treasurySnow = await createTokenAccount(env, game.mint, treasury.publicKey);
+    await mintTo(env, game.mint, game.vault, 7_000_000_000);
+    await game.deposit(pot);
+  });
+
+  it("Refuses to close a game with an active round", async () => {
+    await expectError(game.close(treasurySnow), "RoundStillActive");
+  });
+
+  it("Holds a treasury rotation for a day", async () => {
+    await env.program.methods
+      .setTreasury(treasury.publicKey)
+      .accounts({ gameState: game.address, authority: env.payer.publicKey })
+      .rpc();
+
+    const acceptTreasury = () =>
+      env.program.methods
+        .acceptTreasury()
+        .accounts({ gameState: game.address, newTreasury: treasury.publicKey })
+        .signers([treasury])
+        .rpc();
+
+    await expectError(acceptTreasury(), "TimelockActive");
+
+    await warpTo(env.context, (await now(env.context)) + 24 * 60 * 60);
+    await acceptTreasury();
+    assert.equal((await game.state()).treasury.toBase58(), treasury.publicKey.toBase58());
+  });
+
+  it("Only sends SNOW to a token account of the treasury", async () => {
+    await game.expire();
+    await game.resolve();
+    const authoritySnow = await createTokenAccount(env, game.mint, env.payer.publicKey);
+
+    await expectError(game.close(authoritySnow), "ConstraintTokenOwner");
+  });
+
+  it("Sends leftover SNOW, pot and seed to the treasury and closes the game", async () => {
+    const state = await game.state();
+    const owed = state.potBalanceSol.add(state.nextRoundSeed).toNumber();
+    assert.isAbove(state.nextRoundSeed.toNumber(), 0);
+    const before = await lamports(env.context, treasury.publicKey);
+
+    await game.close(treasurySnow);
+
+    assert.equal((await lamports(env.context, treasury.publicKey)) - before, owed);
+    assert.equal((await tokenBalance(env, game.mint, treasurySnow)).toString(), "7000000000");
+    assert.isNull(await env.context.banksClient.getAccount(game.address));
+    assert.isNull(await env.context.banksClient.getAccount(game.config));
+    assert.isNull(await env.context.banksClient.getAccount(game.vault));
+  });
+
+  it("Refuses to reopen the game under a season it already played", async () => {
+    await expectError(Game.create(env, params, game.mint, 1, 0), "SeasonAlreadyPlayed");
+  });
+
+  it("Reopens the game under a new season", async () => {
+    const next = await Game.create(env, params, game.mint, 1, 1);
+    const player = await next.player();
+    await next.push(player);
+    await next.expire();
+    await next.resolve();
+
+    const record = await next.roundRecord(1);
+    assert.equal(record.winners[0].toBase58(), player.keypair.publicKey.toBase58());
+  });
+});
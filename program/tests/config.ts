@@ -0,0 +1,61 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Env, Game, GameParams, defaultParams, expectError, startEnv } from "./helpers";
+
+describe("⚙️ Snowball Effect: Game Parameters", () => {
+  const year = 365 * 24 * 60 * 60;
+  const tooLong = new anchor.BN(year + 1);
+
+  let env: Env;
+  let game: Game;
+
+  const updateConfig = (params: GameParams) =>
+    env.program.methods
+      .updateConfig(params)
+      .accounts({ gameState: game.address, config: game.config, authority: env.payer.publicKey })
+      .rpc();
+
+  before(async () => {
+    env = await startEnv();
+    game = await Game.create(env, defaultParams());
+  });
+
+  it("Refuses to open a game with a round longer than a year", async () => {
+    await expectError(Game.create(env, defaultParams({ roundDuration: tooLong }), undefined, 2), "InvalidConfig");
+  });
+
+  for (const field of [
+    "roundDuration",
+    "claimExpiry",
+    "withdrawWindow",
+    "timerCap",
+    "snipeWindow",
+    "snipeMinRemaining",
+    "maxRoundLength",
+  ] as const) {
+    it(`Refuses a ${field} longer than a year`, async () => {
+      await expectError(updateConfig(defaultParams({ [field]: tooLong })), "InvalidConfig");
+    });
+  }
+
+  it("Refuses a negative timer cap", async () => {
+    await expectError(updateConfig(defaultParams({ timerCap: new anchor.BN(-1) })), "InvalidConfig");
+  });
+
+  it("Accepts durations of exactly a year", async () => {
+    const longest = new anchor.BN(year);
+    await updateConfig(
+      defaultParams({
+        roundDuration: longest,
+        claimExpiry: longest,
+        withdrawWindow: longest,
+        timerExtension: longest,
+        timerCap: longest,
+        maxRoundLength: longest,
+      })
+    );
+
+    const config = await env.program.account.gameConfig.fetch(game.config);
+    assert.equal(config.pending.roundDuration.toNumber(), year);
+  });
+});
@@ -0,0 +1,228 @@
+import * as anchor from "@coral-xyz/anchor";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID, createSyncNativeInstruction } from "@solana/spl-token";
+import { AccountMeta, Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { assert } from "chai";
+import { Snowball } from "../target/types/snowball";
+import {
+  Env,
+  Game,
+  SnowMint,
+  createMint,
+  createTokenAccount,
+  defaultParams,
+  expectError,
+  fundedPlayer,
+  lamports,
+  mintTo,
+  now,
+  startEnv,
+  tokenBalance,
+  warpTo,
+} from "./helpers";
+
+type SwapVenue = anchor.IdlTypes<Snowball>["SwapVenue"];
+
+// Served by the mock programs built into tests/fixtures (`yarn build:fixtures`).
+const PUMP_PROGRAM_ID = new PublicKey("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
+const RAYDIUM_V4_PROGRAM_ID = new PublicKey("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8");
+const RAYDIUM_AUTHORITY = PublicKey.findProgramAddressSync([Buffer.from("amm authority")], RAYDIUM_V4_PROGRAM_ID)[0];
+const WSOL: SnowMint = { address: NATIVE_MINT, tokenProgram: TOKEN_PROGRAM_ID, decimals: 9, feeBps: 0 };
+
+const readonly = (pubkey: PublicKey) => ({ pubkey, isSigner: false, isWritable: false });
+const writable = (pubkey: PublicKey) => ({ pubkey, isSigner: false, isWritable: true });
+
+describe("🔄 Snowball Effect: SNOW Conversion", () => {
+  const params = defaultParams();
+  const amountIn = new anchor.BN(2_000_000_000);
+
+  let env: Env;
+  let game: Game;
+  let sellAccounts: AccountMeta[];
+
+  const convert = (
+    venue: SwapVenue,
+    swapAccounts: AccountMeta[],
+    minSolOut: anchor.BN,
+    target = game,
+    swapProgram = PUMP_PROGRAM_ID
+  ) =>
+    env.program.methods
+      .convertSnow(venue, amountIn, minSolOut)
+      .accounts({
+        gameState: target.address,
+        config: target.config,
+        authority: env.payer.publicKey,
+        gameSnowVault: target.vault,
+        wsolAccount: null,
+        swapProgram,
+        tokenProgram: target.mint.tokenProgram,
+      })
+      .remainingAccounts(swapAccounts)
+      .rpc();
+
+  before(async () => {
+    env = await startEnv([
+      { name: "mock_pump", programId: PUMP_PROGRAM_ID },
+      { name: "mock_raydium", programId: RAYDIUM_V4_PROGRAM_ID },
+    ]);
+    game = await Game.create(env, params);
+    await mintTo(env, game.mint, game.vault, 10_000_000_000);
+
+    const bondingCurve = Keypair.generate().publicKey;
+    env.context.setAccount(bondingCurve, {
+      lamports: 100 * LAMPORTS_PER_SOL,
+      data: Buffer.alloc(0),
+      owner: PUMP_PROGRAM_ID,
+      executable: false,
+    });
+    const curveSnow = await createTokenAccount(env, game.mint, bondingCurve);
+
+    sellAccounts = [
+      readonly(Keypair.generate().publicKey), // global
+      writable(Keypair.generate().publicKey), // fee_recipient
+      readonly(game.mint.address),
+      writable(bondingCurve),
+      writable(curveSnow), // associated_bonding_curve
+      writable(game.vault), // associated_user
+      writable(game.address), // user
+      readonly(SystemProgram.programId),
+      readonly(game.mint.tokenProgram),
+      readonly(Keypair.generate().publicKey), // associated token program
+      readonly(Keypair.generate().publicKey), // event_authority
+      readonly(PUMP_PROGRAM_ID),
+      readonly(Keypair.generate().publicKey),
+      readonly(Keypair.generate().publicKey),
+      readonly(Keypair.generate().publicKey),
+      readonly(Keypair.generate().publicKey),
+    ];
+  });
+
+  it("Sells vault SNOW on the bonding curve into the pot", async () => {
+    const before = await game.state();
+    const vaultBefore = await tokenBalance(env, game.mint, game.vault);
+
+    await convert({ pumpFun: {} }, sellAccounts, amountIn);
+
+    const after = await game.state();
+    // The mock pays one lamport per base unit.
+    assert.equal(after.potBalanceSol.sub(before.potBalanceSol).toString(), amountIn.toString());
+    const vaultAfter = await tokenBalance(env, game.mint, game.vault);
+    assert.equal((vaultBefore - vaultAfter).toString(), amountIn.toString());
+  });
+
+  it("Requires a minimum SOL output", async () => {
+    await expectError(convert({ pumpFun: {} }, sellAccounts, new anchor.BN(0)), "SlippageExceeded");
+  });
+
+  it("Rejects the vault outside the source position", async () => {
+    const swapped = [...sellAccounts];
+    [swapped[4], swapped[5]] = [swapped[5], swapped[4]];
+
+    await expectError(convert({ pumpFun: {} }, swapped, amountIn), "InvalidSwapAccounts");
+  });
+
+  it("Rejects Raydium AMM v4 for a Token-2022 SNOW", async () => {
+    const token2022Game = await Game.create(env, params, await createMint(env, 100), 2);
+    const raydiumAccounts = Array.from({ length: 18 }, () => ({
+      pubkey: Keypair.generate().publicKey,
+      isSigner: false,
+      isWritable: true,
+    }));
+
+    await expectError(
+      convert({ raydiumV4: {} }, raydiumAccounts, amountIn, token2022Game, RAYDIUM_V4_PROGRAM_ID),
+      "InvalidSwapAccounts"
+    );
+  });
+
+  describe("Raydium AMM v4", () => {
+    let raydiumGame: Game;
+    let manager: Keypair;
+    let poolSnow: PublicKey;
+
+    // A wSOL account of `owner` holding `sol` wrapped lamports.
+    const wrappedSol = async (owner: PublicKey, sol = 0) => {
+      const account = await createTokenAccount(env, WSOL, owner);
+      if (sol > 0) {
+        await env.provider.sendAndConfirm(
+          new Transaction().add(
+            SystemProgram.transfer({ fromPubkey: env.payer.publicKey, toPubkey: account, lamports: sol }),
+            createSyncNativeInstruction(account)
+          )
+        );
+      }
+      return account;
+    };
+
+    // The 18 `swap_base_in` accounts, selling `raydiumGame`'s vault into `wsolAccount`.
+    const swapAccounts = (wsolAccount: PublicKey, poolSol: PublicKey) => [
+      readonly(TOKEN_PROGRAM_ID),
+      writable(Keypair.generate().publicKey), // amm
+      readonly(RAYDIUM_AUTHORITY),
+      writable(Keypair.generate().publicKey), // open orders
+      writable(Keypair.generate().publicKey), // target orders
+      writable(poolSnow), // pool coin vault
+      writable(poolSol), // pool pc vault
+      readonly(Keypair.generate().publicKey), // serum program
+      ...Array.from({ length: 6 }, () => writable(Keypair.generate().publicKey)), // serum market accounts
+      readonly(Keypair.generate().publicKey), // serum vault signer
+      writable(raydiumGame.vault), // user source
+      writable(wsolAccount), // user destination
+      readonly(raydiumGame.address), // user owner
+    ];
+
+    const sellOnRaydium = async (wsolAccount: PublicKey) =>
+      env.program.methods
+        .convertSnow({ raydiumV4: {} }, amountIn, amountIn)
+        .accounts({
+          gameState: raydiumGame.address,
+          config: raydiumGame.config,
+          authority: manager.publicKey,
+          gameSnowVault: raydiumGame.vault,
+          wsolAccount,
+          swapProgram: RAYDIUM_V4_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(swapAccounts(wsolAccount, await wrappedSol(RAYDIUM_AUTHORITY, 10 * LAMPORTS_PER_SOL)))
+        .signers([manager])
+        .rpc();
+
+    before(async () => {
+      raydiumGame = await Game.create(env, params, undefined, 3);
+      await mintTo(env, raydiumGame.mint, raydiumGame.vault, 10_000_000_000);
+      poolSnow = await createTokenAccount(env, raydiumGame.mint, RAYDIUM_AUTHORITY);
+      // A manager other than the fee payer, so fees do not blur the rent refund.
+      manager = fundedPlayer(env.context, 1);
+      await env.program.methods
+        .setLiquidityManager(manager.publicKey)
+        .accounts({ gameState: raydiumGame.address, authority: env.payer.publicKey })
+        .rpc();
+      await warpTo(env.context, (await now(env.context)) + 24 * 60 * 60);
+      await env.program.methods
+        .acceptLiquidityManager()
+        .accounts({ gameState: raydiumGame.address, newManager: manager.publicKey })
+        .signers([manager])
+        .rpc();
+    });
+
+    it("Refuses a wSOL account that already holds SOL", async () => {
+      await expectError(sellOnRaydium(await wrappedSol(raydiumGame.address, 1)), "InvalidSwapAccounts");
+    });
+
+    it("Unwraps the wSOL into the pot and refunds the account rent to the manager", async () => {
+      const wsolAccount = await wrappedSol(raydiumGame.address);
+      const rent = await lamports(env.context, wsolAccount);
+      const before = await raydiumGame.state();
+      const managerBefore = await lamports(env.context, manager.publicKey);
+
+      await sellOnRaydium(wsolAccount);
+
+      // The mock pays one wrapped lamport per base unit.
+      const after = await raydiumGame.state();
+      assert.equal(after.potBalanceSol.sub(before.potBalanceSol).toString(), amountIn.toString());
+      assert.equal((await lamports(env.context, manager.publicKey)) - managerBefore, rent);
+      assert.isNull(await env.context.banksClient.getAccount(wsolAccount));
+      assert.equal((await tokenBalance(env, raydiumGame.mint, poolSnow)).toString(), amountIn.toString());
+    });
+  });
+});
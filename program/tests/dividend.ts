@@ -0,0 +1,91 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Env,
+  Game,
+  Player,
+  defaultParams,
+  expectError,
+  lamports,
+  nextSlot,
+  playerRoundPda,
+  roundRecordPda,
+  startEnv,
+} from "./helpers";
+
+describe("💸 Snowball Effect: Dividends", () => {
+  // 30/20/10 to the winners, 10 to the creator and 30 split per push.
+  const params = defaultParams({ creatorShareBps: 1_000, dividendShareBps: 3_000 });
+  const pot = 10_000_000_000;
+  const distributable = pot - pot / 100;
+  // Seven pushes do not divide the dividend slice evenly.
+  const dividendSlice = (distributable * 3_000) / 10_000;
+  const perPush = Math.floor(dividendSlice / 7);
+
+  let env: Env;
+  let game: Game;
+  let alice: Player;
+  let bob: Player;
+
+  const claimDividend = async (player: Player, round = 1) => {
+    await nextSlot(env.context);
+    return env.program.methods
+      .claimDividend(new anchor.BN(round))
+      .accounts({
+        gameState: game.address,
+        roundRecord: roundRecordPda(game.address, game.seasonId, round),
+        playerRound: playerRoundPda(game.address, game.seasonId, round, player.keypair.publicKey),
+        player: player.keypair.publicKey,
+      })
+      .signers([player.keypair])
+      .rpc();
+  };
+
+  // Claims and returns the dividend the player received, net of the
+  // PlayerRound rent refunded alongside it.
+  const claim = async (player: Player) => {
+    const wallet = player.keypair.publicKey;
+    const rent = await lamports(env.context, playerRoundPda(game.address, game.seasonId, 1, wallet));
+    const before = await lamports(env.context, wallet);
+    await claimDividend(player);
+    return (await lamports(env.context, wallet)) - before - rent;
+  };
+
+  before(async () => {
+    env = await startEnv();
+    game = await Game.create(env, params);
+    alice = await game.player();
+    bob = await game.player();
+    await game.pushMany(alice, 3);
+    await game.pushMany(bob, 4);
+    await game.deposit(pot);
+    await game.expire();
+    await game.resolve();
+  });
+
+  it("Splits the dividend slice evenly per push", async () => {
+    const record = await game.roundRecord(1);
+    assert.equal(record.pushCount.toNumber(), 7);
+    assert.equal(record.dividendPerPush.toNumber(), perPush);
+    assert.equal(record.dividendPool.toNumber(), 7 * perPush);
+    assert.equal((await game.state()).unclaimedDividends.toNumber(), 7 * perPush);
+  });
+
+  it("Rolls the rounding remainder over with the empty winner slot", async () => {
+    const remainder = dividendSlice - 7 * perPush;
+    assert.isAbove(remainder, 0);
+
+    assert.equal((await game.state()).potBalanceSol.toNumber(), (distributable * 1_000) / 10_000 + remainder);
+  });
+
+  it("Pays each player their pushes times the per-push dividend", async () => {
+    assert.equal(await claim(alice), 3 * perPush);
+    assert.equal(await claim(bob), 4 * perPush);
+
+    assert.equal((await game.state()).unclaimedDividends.toNumber(), 0);
+  });
+
+  it("Pays a round's dividend only once", async () => {
+    await expectError(claimDividend(alice), "AccountNotInitialized");
+  });
+});
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+// Test double for the Pump.fun program, loaded at its address by the
+// bankrun tests. `sell` takes the same 16 accounts as the real instruction
+// (see liquidity-manager/src/pump_interface.rs) and pays one lamport per
+// base unit sold from the bonding curve account.
+declare_id!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
+
+#[program]
+pub mod mock_pump {
+    use super::*;
+
+    pub fn sell(ctx: Context<Sell>, amount: u64, min_sol_output: u64) -> Result<()> {
+        let sol_output = amount;
+        require!(sol_output >= min_sol_output, MockPumpError::TooLittleSolReceived);
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.associated_user.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.associated_bonding_curve.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        let curve = ctx.accounts.bonding_curve.to_account_info();
+        let user = ctx.accounts.user.to_account_info();
+        **curve.try_borrow_mut_lamports()? = curve
+            .lamports()
+            .checked_sub(sol_output)
+            .ok_or(MockPumpError::TooLittleSolReceived)?;
+        **user.try_borrow_mut_lamports()? += sol_output;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Sell<'info> {
+    /// CHECK: unused by the mock.
+    pub global: UncheckedAccount<'info>,
+    /// CHECK: unused by the mock.
+    #[account(mut)]
+    pub fee_recipient: UncheckedAccount<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: pays out the SOL; must be owned by this program.
+    #[account(mut, owner = crate::ID)]
+    pub bonding_curve: UncheckedAccount<'info>,
+    #[account(mut, token::mint = mint)]
+    pub associated_bonding_curve: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, token::mint = mint, token::authority = user)]
+    pub associated_user: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    /// CHECK: unused by the mock.
+    pub associated_token_program: UncheckedAccount<'info>,
+    /// CHECK: unused by the mock.
+    pub event_authority: UncheckedAccount<'info>,
+    /// CHECK: unused by the mock.
+    pub program: UncheckedAccount<'info>,
+    /// CHECK: unused by the mock.
+    pub fee_config: UncheckedAccount<'info>,
+    /// CHECK: unused by the mock.
+    pub extra_1: UncheckedAccount<'info>,
+    /// CHECK: unused by the mock.
+    pub extra_2: UncheckedAccount<'info>,
+    /// CHECK: unused by the mock.
+    pub fee_program: UncheckedAccount<'info>,
+}
+
+#[error_code]
+pub enum MockPumpError {
+    #[msg("TooLittleSolReceived")]
+    TooLittleSolReceived,
+}
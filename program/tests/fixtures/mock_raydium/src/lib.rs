@@ -0,0 +1,92 @@
+use anchor_lang::declare_id;
+use anchor_lang::solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use anchor_spl::token::spl_token;
+
+// Test double for Raydium AMM v4, loaded at its address by the bankrun
+// tests. `swap_base_in` takes the same 18 accounts as the real instruction
+// and pays one wrapped SOL base unit per base unit sold, from the pool's pc
+// vault into the user's destination account.
+declare_id!("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8");
+
+const SWAP_BASE_IN: u8 = 9;
+/// Seed of the PDA that owns the pool vaults.
+pub const AUTHORITY_SEED: &[u8] = b"amm authority";
+/// Raydium's `ExceededSlippage`.
+const EXCEEDED_SLIPPAGE: u32 = 30;
+
+#[cfg(not(feature = "no-entrypoint"))]
+anchor_lang::solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let (tag, args) = data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    if *tag != SWAP_BASE_IN || args.len() != 16 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let amount_in = u64::from_le_bytes(args[..8].try_into().unwrap());
+    let min_amount_out = u64::from_le_bytes(args[8..].try_into().unwrap());
+    let amount_out = amount_in;
+    if amount_out < min_amount_out {
+        return Err(ProgramError::Custom(EXCEEDED_SLIPPAGE));
+    }
+
+    if accounts.len() != 18 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let token_program = &accounts[0];
+    let amm_authority = &accounts[2];
+    let pool_coin = &accounts[5];
+    let pool_pc = &accounts[6];
+    let user_source = &accounts[15];
+    let user_destination = &accounts[16];
+    let user_owner = &accounts[17];
+    let (authority, bump) = Pubkey::find_program_address(&[AUTHORITY_SEED], program_id);
+    if *amm_authority.key != authority {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            user_source.key,
+            pool_coin.key,
+            user_owner.key,
+            &[],
+            amount_in,
+        )?,
+        &[
+            user_source.clone(),
+            pool_coin.clone(),
+            user_owner.clone(),
+            token_program.clone(),
+        ],
+    )?;
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            pool_pc.key,
+            user_destination.key,
+            amm_authority.key,
+            &[],
+            amount_out,
+        )?,
+        &[
+            pool_pc.clone(),
+            user_destination.clone(),
+            amm_authority.clone(),
+            token_program.clone(),
+        ],
+        &[&[AUTHORITY_SEED, &[bump]]],
+    )
+}
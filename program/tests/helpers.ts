@@ -0,0 +1,442 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  ACCOUNT_SIZE,
+  ExtensionType,
+  MINT_SIZE,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createInitializeAccount3Instruction,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createMintToInstruction,
+  getAccountLen,
+  getMintLen,
+  unpackAccount,
+} from "@solana/spl-token";
+import { Keypair, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { BankrunProvider, startAnchor } from "anchor-bankrun";
+import { AddedProgram, Clock, ProgramTestContext } from "solana-bankrun";
+import { IDL, Snowball } from "../target/types/snowball";
+import { assert } from "chai";
+
+// Shared bankrun fixture for the Snowball test suites.
+
+export const PROGRAM_ID = new PublicKey("HFn2E5EV2MyUw42n2ZENx8btzeKBeQ9aDyo9GRQQ9ebs");
+
+export type GameParams = anchor.IdlTypes<Snowball>["GameParams"];
+
+export interface Env {
+  context: ProgramTestContext;
+  provider: BankrunProvider;
+  program: Program<Snowball>;
+  payer: Keypair;
+}
+
+// Boots a bankrun bank with the workspace programs loaded.
+export const startEnv = async (extraPrograms: AddedProgram[] = []): Promise<Env> => {
+  const context = await startAnchor("", extraPrograms, []);
+  const provider = new BankrunProvider(context);
+  anchor.setProvider(provider);
+  const program = new Program<Snowball>(IDL, PROGRAM_ID, provider);
+  return { context, provider, program, payer: context.payer };
+};
+
+// A 30/20/10/40 game with one-minute rounds and every optional rule off.
+export const defaultParams = (overrides: Partial<GameParams> = {}): GameParams => ({
+  pushCost: new anchor.BN(1_000_000_000),
+  roundDuration: new anchor.BN(60),
+  winnerSharesBps: [3_000, 2_000, 1_000],
+  creatorShareBps: 4_000,
+  dividendShareBps: 0,
+  carryoverBps: 0,
+  keeperBountyBps: 100,
+  claimExpiry: new anchor.BN(30 * 24 * 60 * 60),
+  maxWithdrawPerRound: new anchor.BN(100_000_000_000),
+  withdrawWindow: new anchor.BN(60 * 60),
+  maxWithdrawPerWindow: new anchor.BN(50_000_000_000),
+  minVaultReserve: new anchor.BN(0),
+  priceCurve: { fixed: {} },
+  burnBps: 0,
+  referralBps: 0,
+  solPushPrice: new anchor.BN(0),
+  repeatWinnerSlots: false,
+  timerExtension: new anchor.BN(0),
+  timerCap: new anchor.BN(0),
+  snipeWindow: new anchor.BN(0),
+  snipeMinRemaining: new anchor.BN(0),
+  maxRoundLength: new anchor.BN(0),
+  ...overrides,
+});
+
+// ---------------------------------------------------------------------------
+// PDAs
+// ---------------------------------------------------------------------------
+
+const u64 = (value: number | anchor.BN) => new anchor.BN(value).toArrayLike(Buffer, "le", 8);
+
+export const pda = (...seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, PROGRAM_ID)[0];
+
+export const gamePda = (gameId: number | anchor.BN) => pda(Buffer.from("game_v4"), u64(gameId));
+export const configPda = (game: PublicKey) => pda(Buffer.from("config"), game.toBuffer());
+export const vaultPda = (game: PublicKey) => pda(Buffer.from("snow_vault"), game.toBuffer());
+const u16 = (value: number) => new anchor.BN(value).toArrayLike(Buffer, "le", 2);
+
+export const roundRecordPda = (game: PublicKey, season: number, round: number | anchor.BN) =>
+  pda(Buffer.from("round"), game.toBuffer(), u16(season), u64(round));
+export const winningsPda = (game: PublicKey, player: PublicKey) =>
+  pda(Buffer.from("winnings"), game.toBuffer(), player.toBuffer());
+export const playerRoundPda = (game: PublicKey, season: number, round: number | anchor.BN, player: PublicKey) =>
+  pda(Buffer.from("player_round"), game.toBuffer(), u16(season), u64(round), player.toBuffer());
+export const referralPda = (game: PublicKey, player: PublicKey) =>
+  pda(Buffer.from("referral"), game.toBuffer(), player.toBuffer());
+export const statsPda = (game: PublicKey, player: PublicKey) =>
+  pda(Buffer.from("player_stats"), game.toBuffer(), player.toBuffer());
+
+// ---------------------------------------------------------------------------
+// Clock and accounts
+// ---------------------------------------------------------------------------
+
+export const now = async (context: ProgramTestContext) =>
+  Number((await context.banksClient.getClock()).unixTimestamp);
+
+// Sets the unix timestamp. Also moves to a new slot, so repeating an
+// identical transaction gets a fresh blockhash.
+export const warpTo = async (context: ProgramTestContext, unixTimestamp: number) => {
+  const clock = await context.banksClient.getClock();
+  const slot = clock.slot + BigInt(1);
+  context.warpToSlot(slot);
+  context.setClock(
+    new Clock(slot, clock.epochStartTimestamp, clock.epoch, clock.leaderScheduleEpoch, BigInt(unixTimestamp))
+  );
+};
+
+export const nextSlot = async (context: ProgramTestContext) => warpTo(context, await now(context));
+
+export const lamports = async (context: ProgramTestContext, address: PublicKey) =>
+  Number((await context.banksClient.getAccount(address))?.lamports ?? 0);
+
+// A fresh keypair holding `sol` SOL.
+export const fundedPlayer = (context: ProgramTestContext, sol = 10) => {
+  const player = Keypair.generate();
+  context.setAccount(player.publicKey, {
+    lamports: sol * anchor.web3.LAMPORTS_PER_SOL,
+    data: Buffer.alloc(0),
+    owner: SystemProgram.programId,
+    executable: false,
+  });
+  return player;
+};
+
+// Asserts that `tx` fails with the named program or framework error.
+export const expectError = async (tx: Promise<unknown>, code: string) => {
+  try {
+    await tx;
+  } catch (err) {
+    const anchorCode = (err as anchor.AnchorError).error?.errorCode?.code;
+    if (anchorCode !== undefined) {
+      assert.equal(anchorCode, code);
+      return;
+    }
+    const number =
+      IDL.errors.find((e) => e.name === code)?.code ??
+      anchor.LangErrorCode[code as keyof typeof anchor.LangErrorCode];
+    assert.isDefined(number, `unknown error ${code}`);
+    const message = String(err);
+    assert(
+      (err as { code?: number }).code === number || message.includes(`0x${number.toString(16)}`),
+      `expected ${code}, got ${message}`
+    );
+    return;
+  }
+  assert.fail(`expected ${code}`);
+};
+
+// ---------------------------------------------------------------------------
+// Tokens
+// ---------------------------------------------------------------------------
+
+export interface SnowMint {
+  address: PublicKey;
+  tokenProgram: PublicKey;
+  decimals: number;
+  feeBps: number;
+}
+
+// Creates a 6-decimal mint owned by the payer. A non-zero `feeBps` makes a
+// Token-2022 mint with an uncapped transfer fee.
+export const createMint = async (env: Env, feeBps = 0, tokenProgram = TOKEN_PROGRAM_ID): Promise<SnowMint> => {
+  const mint = Keypair.generate();
+  const program = feeBps > 0 ? TOKEN_2022_PROGRAM_ID : tokenProgram;
+  const space = feeBps > 0 ? getMintLen([ExtensionType.TransferFeeConfig]) : MINT_SIZE;
+  const rent = await env.context.banksClient.getRent();
+  const tx = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: env.payer.publicKey,
+      newAccountPubkey: mint.publicKey,
+      space,
+      lamports: Number(rent.minimumBalance(BigInt(space))),
+      programId: program,
+    })
+  );
+  if (feeBps > 0) {
+    tx.add(
+      createInitializeTransferFeeConfigInstruction(
+        mint.publicKey,
+        env.payer.publicKey,
+        env.payer.publicKey,
+        feeBps,
+        BigInt("18446744073709551615"),
+        program
+      )
+    );
+  }
+  tx.add(createInitializeMintInstruction(mint.publicKey, 6, env.payer.publicKey, null, program));
+  await env.provider.sendAndConfirm(tx, [mint]);
+  return { address: mint.publicKey, tokenProgram: program, decimals: 6, feeBps };
+};
+
+// Creates a token account of `mint` for `owner`, optionally funded.
+export const createTokenAccount = async (env: Env, mint: SnowMint, owner: PublicKey, amount = 0) => {
+  const account = Keypair.generate();
+  const space = mint.feeBps > 0 ? getAccountLen([ExtensionType.TransferFeeAmount]) : ACCOUNT_SIZE;
+  const rent = await env.context.banksClient.getRent();
+  const tx = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: env.payer.publicKey,
+      newAccountPubkey: account.publicKey,
+      space,
+      lamports: Number(rent.minimumBalance(BigInt(space))),
+      programId: mint.tokenProgram,
+    }),
+    createInitializeAccount3Instruction(account.publicKey, mint.address, owner, mint.tokenProgram)
+  );
+  await env.provider.sendAndConfirm(tx, [account]);
+  if (amount > 0) {
+    await mintTo(env, mint, account.publicKey, amount);
+  }
+  return account.publicKey;
+};
+
+export const mintTo = async (env: Env, mint: SnowMint, destination: PublicKey, amount: number) => {
+  await env.provider.sendAndConfirm(
+    new Transaction().add(
+      createMintToInstruction(mint.address, destination, env.payer.publicKey, amount, [], mint.tokenProgram)
+    )
+  );
+};
+
+export const tokenBalance = async (env: Env, mint: SnowMint, address: PublicKey) => {
+  const info = await env.context.banksClient.getAccount(address);
+  const account = unpackAccount(address, { ...info, data: Buffer.from(info.data) }, mint.tokenProgram);
+  return account.amount;
+};
+
+// ---------------------------------------------------------------------------
+// Game
+// ---------------------------------------------------------------------------
+
+export interface Player {
+  keypair: Keypair;
+  snowAccount: PublicKey;
+}
+
+// One initialized game and the accounts every instruction needs.
+export class Game {
+  readonly address: PublicKey;
+  readonly config: PublicKey;
+  readonly vault: PublicKey;
+
+  private constructor(
+    readonly env: Env,
+    readonly gameId: anchor.BN,
+    readonly seasonId: number,
+    readonly mint: SnowMint,
+    readonly params: GameParams
+  ) {
+    this.address = gamePda(gameId);
+    this.config = configPda(this.address);
+    this.vault = vaultPda(this.address);
+  }
+
+  // Initializes a game owned by the payer. Creates a plain SPL mint unless
+  // one is passed in.
+  static async create(
+    env: Env,
+    params: GameParams = defaultParams(),
+    mint?: SnowMint,
+    gameId: number | anchor.BN = 1,
+    seasonId = 0
+  ): Promise<Game> {
+    const game = new Game(env, new anchor.BN(gameId), seasonId, mint ?? (await createMint(env)), params);
+    await env.program.methods
+      .initializeGame(game.gameId, seasonId, env.payer.publicKey, params)
+      .accounts({
+        gameState: game.address,
+        config: game.config,
+        gameSnowVault: game.vault,
+        snowMint: game.mint.address,
+        firstRoundRecord: roundRecordPda(game.address, seasonId, 1),
+        authority: env.payer.publicKey,
+        tokenProgram: game.mint.tokenProgram,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    return game;
+  }
+
+  get program() {
+    return this.env.program;
+  }
+
+  state() {
+    return this.program.account.gameState.fetch(this.address);
+  }
+
+  async round() {
+    return (await this.state()).roundNumber;
+  }
+
+  // A funded player with a SNOW account holding `snow` base units.
+  async player(snow = 100_000_000_000, sol = 10): Promise<Player> {
+    const keypair = fundedPlayer(this.env.context, sol);
+    const snowAccount = await createTokenAccount(this.env, this.mint, keypair.publicKey, snow);
+    return { keypair, snowAccount };
+  }
+
+  async pushAccounts(
+    player: Player,
+    referrer: PublicKey | null = null,
+    gameSnowVault = this.vault
+  ) {
+    const user = player.keypair.publicKey;
+    return {
+      gameState: this.address,
+      config: this.config,
+      user,
+      userSnowAccount: player.snowAccount,
+      gameSnowVault,
+      snowMint: this.mint.address,
+      winnings: winningsPda(this.address, user),
+      playerRound: playerRoundPda(this.address, this.seasonId, await this.round(), user),
+      referral: referralPda(this.address, user),
+      playerStats: statsPda(this.address, user),
+      referrer: referrer && referralPda(this.address, referrer),
+      tokenProgram: this.mint.tokenProgram,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  async push(player: Player, referrer: PublicKey | null = null) {
+    await nextSlot(this.env.context);
+    return this.program.methods
+      .pushBall()
+      .accounts(await this.pushAccounts(player, referrer))
+      .signers([player.keypair])
+      .rpc();
+  }
+
+  async pushMany(player: Player, count: number) {
+    await nextSlot(this.env.context);
+    return this.program.methods
+      .pushMany(new anchor.BN(count))
+      .accounts(await this.pushAccounts(player))
+      .signers([player.keypair])
+      .rpc();
+  }
+
+  // Adds `amount` lamports from the payer to the pot.
+  deposit(amount: number) {
+    return this.program.methods
+      .depositSolPot(new anchor.BN(amount))
+      .accounts({
+        gameState: this.address,
+        authority: this.env.payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  // Moves the clock past the end of the current round.
+  async expire() {
+    const state = await this.state();
+    const end = state.timerEndTimestamp.toNumber() + 1;
+    await warpTo(this.env.context, Math.max(end, await now(this.env.context)));
+  }
+
+  // Accounts for `resolve_round`. `winners` are the Winnings owners in
+  // slot order; missing slots are passed as `None`.
+  async resolveAccounts(winners: (PublicKey | null)[] = [], keeper = this.env.payer.publicKey) {
+    const slot = (i: number) => winners[i] ?? null;
+    return {
+      gameState: this.address,
+      config: this.config,
+      roundRecord: roundRecordPda(this.address, this.seasonId, await this.round()),
+      keeper,
+      creator: this.env.payer.publicKey,
+      winner1: slot(0) && winningsPda(this.address, slot(0)),
+      winner2: slot(1) && winningsPda(this.address, slot(1)),
+      winner3: slot(2) && winningsPda(this.address, slot(2)),
+      winner1Stats: slot(0) && statsPda(this.address, slot(0)),
+      winner2Stats: slot(1) && statsPda(this.address, slot(1)),
+      winner3Stats: slot(2) && statsPda(this.address, slot(2)),
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  // Resolves the round, passing each last pusher once in its best slot.
+  async resolve(restart = false, keeper?: Keypair) {
+    const state = await this.state();
+    const ranked = [...state.lastPushers].reverse();
+    const winners = ranked.map((key, i) => (ranked.findIndex((k) => k.equals(key)) === i ? key : null));
+    const tx = this.program.methods
+      .resolveRound(restart)
+      .accounts(await this.resolveAccounts(winners, keeper?.publicKey));
+    return keeper ? tx.signers([keeper]).rpc() : tx.rpc();
+  }
+
+  async claimWinnings(player: Keypair) {
+    await nextSlot(this.env.context);
+    return this.program.methods
+      .claimWinnings()
+      .accounts({
+        gameState: this.address,
+        winnings: winningsPda(this.address, player.publicKey),
+        player: player.publicKey,
+      })
+      .signers([player])
+      .rpc();
+  }
+
+  // Closes the game, sending what is left to the treasury and
+  // `treasurySnowAccount`.
+  async close(treasurySnowAccount: PublicKey) {
+    await nextSlot(this.env.context);
+    const state = await this.state();
+    return this.program.methods
+      .closeGame()
+      .accounts({
+        gameState: this.address,
+        config: this.config,
+        gameSnowVault: this.vault,
+        snowMint: this.mint.address,
+        treasury: state.treasury,
+        treasurySnowAccount,
+        authority: this.env.payer.publicKey,
+        tokenProgram: this.mint.tokenProgram,
+      })
+      .rpc();
+  }
+
+  winnings(player: PublicKey) {
+    return this.program.account.winnings.fetch(winningsPda(this.address, player));
+  }
+
+  stats(player: PublicKey) {
+    return this.program.account.playerStats.fetch(statsPda(this.address, player));
+  }
+
+  roundRecord(round: number | anchor.BN) {
+    return this.program.account.roundRecord.fetch(roundRecordPda(this.address, this.seasonId, round));
+  }
+}
@@ -0,0 +1,59 @@
+import { assert } from "chai";
+import { Env, Game, defaultParams, expectError, fundedPlayer, lamports, now, roundRecordPda, startEnv } from "./helpers";
+
+describe("🧹 Snowball Effect: Keeper", () => {
+  const params = defaultParams();
+  const pot = 10_000_000_000;
+
+  let env: Env;
+  let nextGameId = 1;
+
+  // A game with one push and a funded pot.
+  const fundedGame = async () => {
+    const game = await Game.create(env, params, undefined, nextGameId++);
+    await game.push(await game.player());
+    await game.deposit(pot);
+    return game;
+  };
+
+  before(async () => {
+    env = await startEnv();
+  });
+
+  it("Refuses to resolve a round before its timer ends", async () => {
+    const game = await fundedGame();
+
+    await expectError(game.resolve(false, fundedPlayer(env.context, 1)), "RoundStillActive");
+  });
+
+  it("Pays any keeper the bounty for resolving an expired round", async () => {
+    const game = await fundedGame();
+    const keeper = fundedPlayer(env.context, 1);
+    await game.expire();
+
+    const before = await lamports(env.context, keeper.publicKey);
+    await game.resolve(false, keeper);
+
+    // The keeper also pays the rent of the round record.
+    const recordRent = await lamports(env.context, roundRecordPda(game.address, game.seasonId, 1));
+    assert.equal((await lamports(env.context, keeper.publicKey)) - before + recordRent, pot / 100);
+    const record = await game.roundRecord(1);
+    assert.equal(record.keeper.toBase58(), keeper.publicKey.toBase58());
+    assert.equal(record.keeperBounty.toNumber(), pot / 100);
+    assert.isFalse((await game.state()).isActive);
+  });
+
+  it("Opens the next round in the same transaction on restart", async () => {
+    const game = await fundedGame();
+    await game.expire();
+
+    await game.resolve(true, fundedPlayer(env.context, 1));
+
+    const state = await game.state();
+    assert.isTrue(state.isActive);
+    assert.equal(state.roundNumber.toNumber(), 2);
+    assert.equal(state.pushCount.toNumber(), 0);
+    assert.equal(state.timerEndTimestamp.toNumber(), (await now(env.context)) + params.roundDuration.toNumber());
+    await game.push(await game.player());
+  });
+});
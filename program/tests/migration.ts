@@ -0,0 +1,146 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Env, Game, defaultParams, expectError, nextSlot, pda, startEnv } from "./helpers";
+
+describe("🧳 Snowball Effect: State Migration", () => {
+  let env: Env;
+  let game: Game;
+
+  const discriminator = anchor.BorshAccountsCoder.accountDiscriminator("GameState");
+
+  before(async () => {
+    env = await startEnv();
+    game = await Game.create(env, defaultParams());
+  });
+
+  it("Moves the lamports of the original game_v4 PDA into the pot", async () => {
+    const legacySeed = Buffer.from("game_v4");
+    const legacyPDA = pda(legacySeed);
+    const lamports = 5_000_000_000;
+
+    const legacyData = Buffer.concat([
+      discriminator,
+      Buffer.from([0]), // is_active
+      new anchor.BN(3).toArrayLike(Buffer, "le", 8), // round_number
+      Buffer.alloc(8), // timer_end_timestamp
+      Buffer.alloc(8), // snow_collected
+      new anchor.BN(lamports).toArrayLike(Buffer, "le", 8), // pot_balance_sol
+      Buffer.alloc(4), // last_pushers
+      Buffer.alloc(8), // push_count
+      env.payer.publicKey.toBuffer(), // authority
+      env.payer.publicKey.toBuffer(), // liquidity_manager
+      Buffer.alloc(3 * 32), // room for three last_pushers
+    ]);
+    assert.equal(legacyData.length, 213);
+    env.context.setAccount(legacyPDA, {
+      lamports,
+      data: legacyData,
+      owner: env.program.programId,
+      executable: false,
+    });
+
+    const before = await game.state();
+    await env.program.methods
+      .drainLegacy(legacySeed)
+      .accounts({
+        gameState: game.address,
+        legacyState: legacyPDA,
+        legacyVault: null,
+        gameSnowVault: game.vault,
+        snowMint: game.mint.address,
+        authority: env.payer.publicKey,
+        tokenProgram: game.mint.tokenProgram,
+      })
+      .rpc();
+
+    const after = await game.state();
+    assert.equal(after.potBalanceSol.sub(before.potBalanceSol).toNumber(), lamports);
+    assert.isNull(await env.context.banksClient.getAccount(legacyPDA));
+  });
+
+  it("Refuses to drain a current game through a legacy-looking seed", async () => {
+    // "game_v4" followed by the game id derives the current game's PDA.
+    const other = await Game.create(env, defaultParams(), game.mint, 3);
+    const seed = Buffer.concat([Buffer.from("game_v4"), other.gameId.toArrayLike(Buffer, "le", 8)]);
+    assert.equal(pda(seed).toBase58(), other.address.toBase58());
+
+    await expectError(
+      env.program.methods
+        .drainLegacy(seed)
+        .accounts({
+          gameState: game.address,
+          legacyState: other.address,
+          legacyVault: null,
+          gameSnowVault: game.vault,
+          snowMint: game.mint.address,
+          authority: env.payer.publicKey,
+          tokenProgram: game.mint.tokenProgram,
+        })
+        .rpc(),
+      "InvalidLayout"
+    );
+  });
+
+  it("Upgrades a version 1 GameState written at the old size", async () => {
+    const v1Game = await Game.create(env, defaultParams(), game.mint, 2);
+    const player = await v1Game.player();
+    await v1Game.push(player);
+    await v1Game.deposit(3_000_000_000);
+    const before = await v1Game.state();
+
+    // Version 1 ends at unclaimed_referral_snow: drop the treasury,
+    // pending_treasury and treasury_eta fields (32 + 33 + 8 bytes).
+    const treasuryFields = 73;
+    const account = await env.context.banksClient.getAccount(v1Game.address);
+    const current = await env.program.coder.accounts.encode("GameState", before);
+    const fields = current.subarray(0, current.length - treasuryFields);
+    const data = Buffer.concat([fields, Buffer.alloc(account.data.length - treasuryFields - fields.length)]);
+    data[8] = 1; // version
+    env.context.setAccount(v1Game.address, { ...account, data });
+
+    await expectError(v1Game.deposit(1), "InvalidLayout");
+
+    await env.program.methods
+      .migrateState(v1Game.gameId)
+      .accounts({
+        gameState: v1Game.address,
+        authority: env.payer.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const after = await v1Game.state();
+    assert.equal(after.version, 2);
+    assert.equal((await env.context.banksClient.getAccount(v1Game.address)).data.length, account.data.length);
+    assert.equal(after.potBalanceSol.toNumber(), before.potBalanceSol.toNumber());
+    assert.equal(after.pushCount.toNumber(), 1);
+    assert.equal(after.lastPushers[0].toBase58(), player.keypair.publicKey.toBase58());
+    assert.equal(after.liquidityManager.toBase58(), before.liquidityManager.toBase58());
+    assert.equal(after.treasury.toBase58(), env.payer.publicKey.toBase58());
+    await nextSlot(env.context);
+    await v1Game.deposit(1);
+  });
+
+  it("Refuses to migrate a GameState that is already current", async () => {
+    await expectError(
+      env.program.methods
+        .migrateState(game.gameId)
+        .accounts({
+          gameState: game.address,
+          authority: env.payer.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc(),
+      "InvalidLayout"
+    );
+  });
+
+  it("Refuses a GameState of another layout version", async () => {
+    const account = await env.context.banksClient.getAccount(game.address);
+    const data = Buffer.from(account.data);
+    data[8] = 0; // version
+    env.context.setAccount(game.address, { ...account, data });
+
+    await expectError(game.deposit(1_000_000), "InvalidLayout");
+  });
+});
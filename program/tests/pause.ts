@@ -0,0 +1,80 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Env, Game, Player, defaultParams, expectError, fundedPlayer, now, startEnv, warpTo } from "./helpers";
+
+describe("⏸️ Snowball Effect: Pause", () => {
+  let env: Env;
+  let game: Game;
+  let player: Player;
+  let guardian: anchor.web3.Keypair;
+
+  const pause = (signer = env.payer) =>
+    env.program.methods
+      .pauseGame()
+      .accounts({ gameState: game.address, signer: signer.publicKey })
+      .signers(signer === env.payer ? [] : [signer])
+      .rpc();
+
+  const unpause = (signer = env.payer) =>
+    env.program.methods
+      .unpauseGame()
+      .accounts({ gameState: game.address, authority: signer.publicKey })
+      .signers(signer === env.payer ? [] : [signer])
+      .rpc();
+
+  const reset = () =>
+    env.program.methods
+      .resetGame()
+      .accounts({ gameState: game.address, config: game.config, authority: env.payer.publicKey })
+      .rpc();
+
+  before(async () => {
+    env = await startEnv();
+    game = await Game.create(env, defaultParams());
+    player = await game.player();
+    guardian = fundedPlayer(env.context, 1);
+    await env.program.methods
+      .setGuardian(guardian.publicKey)
+      .accounts({ gameState: game.address, authority: env.payer.publicKey })
+      .rpc();
+  });
+
+  it("Refuses pushes while paused", async () => {
+    await game.push(player);
+    await pause(guardian);
+
+    await expectError(game.push(player), "GamePaused");
+  });
+
+  it("Only lets the authority unpause", async () => {
+    await expectError(unpause(guardian), "Unauthorized");
+  });
+
+  it("Pushes the round timer back by the time spent paused", async () => {
+    const state = await game.state();
+    const pausedFor = 10 * 60;
+    await warpTo(env.context, state.pausedAt.toNumber() + pausedFor);
+
+    await unpause();
+
+    const after = await game.state();
+    assert.isFalse(after.isPaused);
+    assert.equal(after.timerEndTimestamp.sub(state.timerEndTimestamp).toNumber(), pausedFor);
+    assert.isAbove(after.timerEndTimestamp.toNumber(), await now(env.context));
+    await game.push(player);
+  });
+
+  it("Refuses to start the next round while paused", async () => {
+    await game.expire();
+    await game.resolve();
+    await pause();
+
+    await expectError(reset(), "GamePaused");
+
+    await unpause();
+    await reset();
+    const state = await game.state();
+    assert.isTrue(state.isActive);
+    assert.equal(state.timerEndTimestamp.toNumber(), (await now(env.context)) + game.params.roundDuration.toNumber());
+  });
+});
@@ -0,0 +1,73 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Env, Game, GameParams, Player, defaultParams, startEnv, tokenBalance } from "./helpers";
+
+describe("📈 Snowball Effect: Push Pricing", () => {
+  const cost = 1_000_000_000;
+
+  let env: Env;
+  let nextGameId = 1;
+
+  const quote = async (game: Game) =>
+    (await env.program.methods.quotePush().accounts({ gameState: game.address, config: game.config }).view()).toNumber();
+
+  // SNOW the player spends on `push`.
+  const spent = async (game: Game, player: Player, push: () => Promise<unknown>) => {
+    const before = await tokenBalance(env, game.mint, player.snowAccount);
+    await push();
+    return Number(before - (await tokenBalance(env, game.mint, player.snowAccount)));
+  };
+
+  const newGame = (priceCurve: GameParams["priceCurve"]) =>
+    Game.create(env, defaultParams({ priceCurve }), undefined, nextGameId++);
+
+  before(async () => {
+    env = await startEnv();
+  });
+
+  it("Charges the same for every push on a fixed curve", async () => {
+    const game = await newGame({ fixed: {} });
+    const player = await game.player();
+
+    assert.equal(await spent(game, player, () => game.push(player)), cost);
+    assert.equal(await spent(game, player, () => game.pushMany(player, 3)), 3 * cost);
+    assert.equal(await quote(game), cost);
+  });
+
+  it("Adds the increment per push on a linear curve", async () => {
+    const increment = 500_000_000;
+    const game = await newGame({ linear: { increment: new anchor.BN(increment) } });
+    const player = await game.player();
+
+    assert.equal(await quote(game), cost);
+    assert.equal(await spent(game, player, () => game.push(player)), cost);
+    assert.equal(await quote(game), cost + increment);
+
+    // Pushes 1, 2 and 3 of the round, priced one by one.
+    const batch = 3 * cost + (1 + 2 + 3) * increment;
+    assert.equal(await spent(game, player, () => game.pushMany(player, 3)), batch);
+    assert.equal(await quote(game), cost + 4 * increment);
+  });
+
+  it("Compounds the growth per push on an exponential curve", async () => {
+    const game = await newGame({ exponential: { growthBps: 1_000 } });
+    const player = await game.player();
+
+    assert.equal(await quote(game), cost);
+    assert.equal(await spent(game, player, () => game.push(player)), cost);
+    assert.equal(await quote(game), 1_100_000_000);
+    assert.equal(await spent(game, player, () => game.push(player)), 1_100_000_000);
+    assert.equal(await quote(game), 1_210_000_000);
+  });
+
+  it("Starts each round back at the push cost", async () => {
+    const game = await newGame({ linear: { increment: new anchor.BN(cost) } });
+    await game.pushMany(await game.player(), 2);
+    assert.equal(await quote(game), 3 * cost);
+
+    await game.expire();
+    await game.resolve(true);
+
+    assert.equal(await quote(game), cost);
+  });
+});
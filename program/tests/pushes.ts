@@ -0,0 +1,73 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Env,
+  Game,
+  Player,
+  defaultParams,
+  expectError,
+  playerRoundPda,
+  startEnv,
+  statsPda,
+  tokenBalance,
+  winningsPda,
+} from "./helpers";
+
+describe("💸 Snowball Effect: SOL and Batch Pushes", () => {
+  const params = defaultParams({ solPushPrice: new anchor.BN(10_000_000) });
+
+  let env: Env;
+  let game: Game;
+  let player: Player;
+
+  before(async () => {
+    env = await startEnv();
+    game = await Game.create(env, params);
+    player = await game.player();
+  });
+
+  it("Accepts a push paid in SOL into the pot", async () => {
+    const before = await game.state();
+    const user = player.keypair.publicKey;
+
+    await env.program.methods
+      .pushBallSol()
+      .accounts({
+        gameState: game.address,
+        config: game.config,
+        user,
+        winnings: winningsPda(game.address, user),
+        playerRound: playerRoundPda(game.address, game.seasonId, before.roundNumber, user),
+        playerStats: statsPda(game.address, user),
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([player.keypair])
+      .rpc();
+
+    const after = await game.state();
+    assert.equal(
+      after.potBalanceSol.sub(before.potBalanceSol).toString(),
+      params.solPushPrice.toString()
+    );
+    assert.equal(after.pushCount.toNumber(), before.pushCount.toNumber() + 1);
+    assert.equal(after.lastPushers[after.lastPushers.length - 1].toBase58(), user.toBase58());
+  });
+
+  it("Buys several pushes with one transfer and one winner slot", async () => {
+    const before = await game.state();
+    const vaultBefore = await tokenBalance(env, game.mint, game.vault);
+
+    await game.pushMany(player, 3);
+
+    const after = await game.state();
+    assert.equal(after.pushCount.toNumber(), before.pushCount.toNumber() + 3);
+    const vaultAfter = await tokenBalance(env, game.mint, game.vault);
+    assert.equal((vaultAfter - vaultBefore).toString(), params.pushCost.muln(3).toString());
+    const slots = after.lastPushers.filter((key) => key.equals(player.keypair.publicKey));
+    assert.equal(slots.length, 1);
+  });
+
+  it("Rejects an empty batch", async () => {
+    await expectError(game.pushMany(player, 0), "InvalidPushCount");
+  });
+});
@@ -0,0 +1,84 @@
+import { assert } from "chai";
+import {
+  Env,
+  Game,
+  Player,
+  defaultParams,
+  expectError,
+  referralPda,
+  startEnv,
+  tokenBalance,
+} from "./helpers";
+
+describe("🤝 Snowball Effect: Referrals", () => {
+  const params = defaultParams({ referralBps: 500 });
+  const share = params.pushCost.muln(params.referralBps).divn(10_000);
+
+  let env: Env;
+  let game: Game;
+  let referrer: Player;
+  let referred: Player;
+
+  const earned = async (player: Player) =>
+    (await env.program.account.referral.fetch(referralPda(game.address, player.keypair.publicKey))).earned;
+
+  before(async () => {
+    env = await startEnv();
+    game = await Game.create(env, params);
+    referrer = await game.player();
+    referred = await game.player();
+    await game.push(referrer);
+  });
+
+  it("Links the referrer on the first push without paying it", async () => {
+    await game.push(referred, referrer.keypair.publicKey);
+
+    const link = await env.program.account.referral.fetch(referralPda(game.address, referred.keypair.publicKey));
+    assert.equal(link.referrer?.toBase58(), referrer.keypair.publicKey.toBase58());
+    assert.equal((await earned(referrer)).toNumber(), 0);
+  });
+
+  it("Credits the referrer on later pushes", async () => {
+    const before = await game.state();
+    await game.push(referred, referrer.keypair.publicKey);
+
+    assert.equal((await earned(referrer)).toString(), share.toString());
+    const after = await game.state();
+    assert.equal(after.snowCollected.sub(before.snowCollected).toString(), params.pushCost.sub(share).toString());
+  });
+
+  it("Refuses a later push that leaves out the linked referrer", async () => {
+    await expectError(game.push(referred), "ReferrerMismatch");
+
+    assert.equal((await earned(referrer)).toString(), share.toString());
+  });
+
+  it("Rejects a referrer account other than the linked one", async () => {
+    const other = await game.player();
+    await game.push(other);
+
+    await expectError(game.push(referred, other.keypair.publicKey), "ReferrerMismatch");
+  });
+
+  it("Pays the earned SNOW to the referrer", async () => {
+    const before = await tokenBalance(env, game.mint, referrer.snowAccount);
+    await env.program.methods
+      .claimReferralRewards()
+      .accounts({
+        gameState: game.address,
+        referral: referralPda(game.address, referrer.keypair.publicKey),
+        referrer: referrer.keypair.publicKey,
+        gameSnowVault: game.vault,
+        snowMint: game.mint.address,
+        referrerSnowAccount: referrer.snowAccount,
+        tokenProgram: game.mint.tokenProgram,
+      })
+      .signers([referrer.keypair])
+      .rpc();
+
+    const after = await tokenBalance(env, game.mint, referrer.snowAccount);
+    assert.equal((after - before).toString(), share.toString());
+    assert.equal((await earned(referrer)).toNumber(), 0);
+    assert.equal((await game.state()).unclaimedReferralSnow.toNumber(), 0);
+  });
+});
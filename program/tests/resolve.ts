@@ -0,0 +1,132 @@
+import { assert } from "chai";
+import {
+  Env,
+  Game,
+  GameParams,
+  Player,
+  defaultParams,
+  expectError,
+  lamports,
+  startEnv,
+  statsPda,
+} from "./helpers";
+
+describe("🏆 Snowball Effect: Resolve and Claim", () => {
+  const pot = 10_000_000_000;
+  // 1% keeper bounty off the top.
+  const distributable = pot - pot / 100;
+  const share = (bps: number) => (distributable * bps) / 10_000;
+
+  let env: Env;
+  let nextGameId = 1;
+
+  // A game with a funded pot where `order` (indexes into a fresh set of
+  // players) pushes in sequence, moved past its round end.
+  const playRound = async (order: number[], params: GameParams = defaultParams()) => {
+    const game = await Game.create(env, params, undefined, nextGameId++);
+    const players: Player[] = [];
+    for (let i = 0; i <= Math.max(...order); i++) {
+      players.push(await game.player());
+    }
+    for (const i of order) {
+      await game.push(players[i]);
+    }
+    await game.deposit(pot);
+    await game.expire();
+    return { game, players };
+  };
+
+  // Claims and returns the lamports the player received.
+  const claim = async (game: Game, player: Player) => {
+    const before = await lamports(env.context, player.keypair.publicKey);
+    await game.claimWinnings(player.keypair);
+    return (await lamports(env.context, player.keypair.publicKey)) - before;
+  };
+
+  before(async () => {
+    env = await startEnv();
+  });
+
+  it("Pays three pushers 30/20/10 from the last pusher back", async () => {
+    const { game, players } = await playRound([0, 1, 2]);
+    await game.resolve();
+
+    assert.equal(await claim(game, players[2]), share(3_000));
+    assert.equal(await claim(game, players[1]), share(2_000));
+    assert.equal(await claim(game, players[0]), share(1_000));
+    assert.equal((await game.state()).unclaimedWinnings.toNumber(), 0);
+  });
+
+  it("Rolls the empty third slot over with two pushers", async () => {
+    const { game, players } = await playRound([0, 1]);
+    await game.resolve();
+
+    assert.equal(await claim(game, players[1]), share(3_000));
+    assert.equal(await claim(game, players[0]), share(2_000));
+    assert.equal((await game.state()).potBalanceSol.toNumber(), share(1_000));
+  });
+
+  it("Rolls both empty slots over with one pusher", async () => {
+    const { game, players } = await playRound([0]);
+    await game.resolve();
+
+    assert.equal(await claim(game, players[0]), share(3_000));
+    assert.equal((await game.state()).potBalanceSol.toNumber(), share(3_000));
+  });
+
+  it("Credits a repeated pusher every slot they hold through one account", async () => {
+    const { game, players } = await playRound([0, 1, 0], defaultParams({ repeatWinnerSlots: true }));
+    await game.resolve();
+
+    assert.equal(await claim(game, players[0]), share(3_000) + share(1_000));
+    assert.equal(await claim(game, players[1]), share(2_000));
+
+    const stats = await game.stats(players[0].keypair.publicKey);
+    assert.deepEqual(stats.wins.map((wins) => wins.toNumber()), [1, 0, 1]);
+    assert.equal(stats.totalSolWon.toNumber(), share(3_000) + share(1_000));
+  });
+
+  it("Rejects a repeated pusher's account in their lower slot", async () => {
+    const { game, players } = await playRound([0, 1, 0], defaultParams({ repeatWinnerSlots: true }));
+    const [first, second] = players.map((p) => p.keypair.publicKey);
+
+    await expectError(
+      env.program.methods
+        .resolveRound(false)
+        .accounts(await game.resolveAccounts([first, second, first]))
+        .rpc(),
+      "WinnerMismatch"
+    );
+  });
+
+  it("Rejects accounts in slots no pusher holds", async () => {
+    const { game, players } = await playRound([0]);
+    const winner = players[0].keypair.publicKey;
+
+    // A stale copy of the winner's account in the empty second slot would
+    // be written back after the credited one.
+    await expectError(
+      env.program.methods
+        .resolveRound(false)
+        .accounts(await game.resolveAccounts([winner, winner]))
+        .rpc(),
+      "WinnerMismatch"
+    );
+  });
+
+  it("Rejects stats accounts in slots no pusher holds", async () => {
+    const { game, players } = await playRound([0]);
+    const winner = players[0].keypair.publicKey;
+
+    await expectError(
+      env.program.methods
+        .resolveRound(false)
+        .accounts({
+          ...(await game.resolveAccounts([winner])),
+          winner2Stats: statsPda(game.address, winner),
+        })
+        .rpc(),
+      "WinnerMismatch"
+    );
+  });
+});
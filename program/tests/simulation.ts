@@ -1,43 +1,98 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
-import { Snowball } from "../target/types/snowball";
 import { assert } from "chai";
+import {
+  Env,
+  Game,
+  Player,
+  createTokenAccount,
+  defaultParams,
+  lamports,
+  now,
+  startEnv,
+  tokenBalance,
+} from "./helpers";
 
 describe("❄️ Snowball Effect: Full Simulation", () => {
-  // Configure the client to use the local cluster.
-  const provider = anchor.AnchorProvider.env();
-  anchor.setProvider(provider);
+  const params = defaultParams();
+  const pushCost = params.pushCost.toNumber();
+  const pot = 20_000_000_000;
+  // 1% keeper bounty off the top.
+  const distributable = pot - pot / 100;
+  const share = (bps: number) => (distributable * bps) / 10_000;
 
-  const program = anchor.workspace.Snowball as Program<Snowball>;
+  let env: Env;
+  let game: Game;
+  let user1: Player;
+  let user2: Player;
+  let managerSnow: anchor.web3.PublicKey;
 
-  // Test State
-  let gamePDA: anchor.web3.PublicKey;
-  let snowMint: anchor.web3.PublicKey;
-  let user1 = anchor.web3.Keypair.generate();
-  let user2 = anchor.web3.Keypair.generate();
-  let liquidityManager = anchor.web3.Keypair.generate();
+  before(async () => {
+    env = await startEnv();
+  });
 
   it("Is initialized!", async () => {
-    // 1. Create a dummy game instance
-    // 2. Mint 'MockSNOW' to users
-    console.log("🚀 Starting Simulation...");
+    game = await Game.create(env, params);
+    user1 = await game.player();
+    user2 = await game.player();
+    managerSnow = await createTokenAccount(env, game.mint, env.payer.publicKey);
+
+    const state = await game.state();
+    assert.isTrue(state.isActive);
+    assert.equal(state.roundNumber.toNumber(), 1);
+    assert.equal(state.liquidityManager.toBase58(), env.payer.publicKey.toBase58());
   });
 
   it("User 1 Pushes the Ball", async () => {
-    // 1. User 1 calls push_ball
-    // 2. Check Timer Reset
-    // 3. Check SNOW deducted
+    const before = await tokenBalance(env, game.mint, user1.snowAccount);
+    await game.push(user1);
+
+    const state = await game.state();
+    assert.equal(state.timerEndTimestamp.toNumber(), (await now(env.context)) + params.roundDuration.toNumber());
+    assert.equal(before - (await tokenBalance(env, game.mint, user1.snowAccount)), BigInt(pushCost));
+    assert.equal(state.lastPushers[0].toBase58(), user1.keypair.publicKey.toBase58());
+
+    await game.push(user2);
   });
 
   it("Liquidity Manager Swaps SNOW for SOL", async () => {
-    // 1. Bot calls withdraw_snow
-    // 2. Bot calls deposit_sol
-    // 3. Check Pot Balance
+    await env.program.methods
+      .withdrawSnowForSwap(new anchor.BN(2 * pushCost))
+      .accounts({
+        gameState: game.address,
+        config: game.config,
+        authority: env.payer.publicKey,
+        gameSnowVault: game.vault,
+        snowMint: game.mint.address,
+        managerSnowAccount: managerSnow,
+        tokenProgram: game.mint.tokenProgram,
+      })
+      .rpc();
+    await game.deposit(pot);
+
+    const state = await game.state();
+    assert.equal((await tokenBalance(env, game.mint, managerSnow)).toString(), String(2 * pushCost));
+    assert.equal(state.snowCollected.toNumber(), 0);
+    assert.equal(state.potBalanceSol.toNumber(), pot);
   });
 
   it("Game Ends & Payouts Distributed", async () => {
-    // 1. Wait for timer to expire
-    // 2. Call resolve_round
-    // 3. Verify User 1 (Winner) received SOL
+    await game.expire();
+    await game.resolve(true);
+
+    const record = await game.roundRecord(1);
+    assert.equal(record.creatorPayout.toNumber(), share(4_000));
+    assert.equal(record.winnerPayouts[0].toNumber(), share(3_000));
+    assert.equal(record.winnerPayouts[1].toNumber(), share(2_000));
+    assert.equal(record.winnerPayouts[2].toNumber(), 0);
+
+    const before = await lamports(env.context, user2.keypair.publicKey);
+    await game.claimWinnings(user2.keypair);
+    assert.equal((await lamports(env.context, user2.keypair.publicKey)) - before, share(3_000));
+
+    // The empty third slot stays in the pot of the round that follows.
+    const state = await game.state();
+    assert.isTrue(state.isActive);
+    assert.equal(state.roundNumber.toNumber(), 2);
+    assert.equal(state.potBalanceSol.toNumber(), share(1_000));
   });
 });
@@ -0,0 +1,26 @@
+import { assert } from "chai";
+import { Env, Game, Player, defaultParams, startEnv } from "./helpers";
+
+describe("📊 Snowball Effect: Player Stats", () => {
+  const params = defaultParams();
+
+  let env: Env;
+  let game: Game;
+  let player: Player;
+
+  before(async () => {
+    env = await startEnv();
+    game = await Game.create(env, params);
+    player = await game.player();
+  });
+
+  it("Counts pushes, rounds and SNOW spent", async () => {
+    await game.push(player);
+    await game.pushMany(player, 2);
+
+    const stats = await game.stats(player.keypair.publicKey);
+    assert.equal(stats.totalPushes.toNumber(), 3);
+    assert.equal(stats.roundsPlayed.toNumber(), 1);
+    assert.equal(stats.snowSpent.toString(), params.pushCost.muln(3).toString());
+  });
+});
@@ -0,0 +1,134 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Env,
+  Game,
+  Player,
+  createTokenAccount,
+  defaultParams,
+  expectError,
+  now,
+  playerRoundPda,
+  referralPda,
+  roundRecordPda,
+  startEnv,
+  warpTo,
+  winningsPda,
+} from "./helpers";
+
+describe("🧹 Snowball Effect: Expired Claims", () => {
+  const params = defaultParams({ creatorShareBps: 3_000, dividendShareBps: 1_000, referralBps: 500 });
+  const pot = 10_000_000_000;
+  // Three pushes share 10% of the pot after the 1% keeper bounty.
+  const perPush = (pot - pot / 100) / 10 / 3;
+
+  let env: Env;
+  let game: Game;
+  let referrer: Player;
+  let referred: Player;
+
+  const sweepDividend = (player: Player) =>
+    env.program.methods
+      .sweepExpiredDividend(new anchor.BN(1))
+      .accounts({
+        gameState: game.address,
+        config: game.config,
+        roundRecord: roundRecordPda(game.address, game.seasonId, 1),
+        playerRound: playerRoundPda(game.address, game.seasonId, 1, player.keypair.publicKey),
+        player: player.keypair.publicKey,
+        authority: env.payer.publicKey,
+      })
+      .rpc();
+
+  const sweepReferral = () =>
+    env.program.methods
+      .sweepExpiredReferral()
+      .accounts({
+        gameState: game.address,
+        config: game.config,
+        referral: referralPda(game.address, referrer.keypair.publicKey),
+        authority: env.payer.publicKey,
+      })
+      .rpc();
+
+  const sweepWinnings = (player: Player) =>
+    env.program.methods
+      .sweepExpiredWinnings()
+      .accounts({
+        gameState: game.address,
+        config: game.config,
+        winnings: winningsPda(game.address, player.keypair.publicKey),
+        authority: env.payer.publicKey,
+      })
+      .rpc();
+
+  before(async () => {
+    env = await startEnv();
+    game = await Game.create(env, params);
+    referrer = await game.player();
+    referred = await game.player();
+
+    await game.push(referrer);
+    await game.push(referred, referrer.keypair.publicKey);
+    await game.push(referred, referrer.keypair.publicKey);
+    await game.deposit(pot);
+    await game.expire();
+    await game.resolve();
+  });
+
+  it("Keeps the game open while claims are outstanding", async () => {
+    const treasurySnow = await createTokenAccount(env, game.mint, env.payer.publicKey);
+
+    await expectError(game.close(treasurySnow), "UnclaimedWinnings");
+  });
+
+  it("Refuses to sweep before the claim expiry", async () => {
+    await expectError(sweepDividend(referrer), "ClaimNotExpired");
+    await expectError(sweepReferral(), "ClaimNotExpired");
+  });
+
+  it("Moves expired dividends into the next round's seed", async () => {
+    await warpTo(env.context, (await now(env.context)) + params.claimExpiry.toNumber());
+    const before = await game.state();
+
+    await sweepDividend(referrer);
+    await sweepDividend(referred);
+
+    const after = await game.state();
+    assert.equal(after.nextRoundSeed.sub(before.nextRoundSeed).toNumber(), perPush * 3);
+    assert.equal(after.potBalanceSol.toNumber(), before.potBalanceSol.toNumber());
+    assert.equal(after.unclaimedDividends.toNumber(), 0);
+    const playerRound = playerRoundPda(game.address, game.seasonId, 1, referred.keypair.publicKey);
+    assert.isNull(await env.context.banksClient.getAccount(playerRound));
+  });
+
+  it("Releases expired referral SNOW", async () => {
+    await sweepReferral();
+
+    const referral = await env.program.account.referral.fetch(referralPda(game.address, referrer.keypair.publicKey));
+    assert.equal(referral.earned.toNumber(), 0);
+    assert.equal((await game.state()).unclaimedReferralSnow.toNumber(), 0);
+  });
+
+  it("Moves expired winnings into the next round's seed", async () => {
+    const before = await game.state();
+    const owed =
+      (await game.winnings(referrer.keypair.publicKey)).amount.toNumber() +
+      (await game.winnings(referred.keypair.publicKey)).amount.toNumber();
+
+    await sweepWinnings(referrer);
+    await sweepWinnings(referred);
+
+    const after = await game.state();
+    assert.equal(after.nextRoundSeed.sub(before.nextRoundSeed).toNumber(), owed);
+    assert.equal(after.potBalanceSol.toNumber(), before.potBalanceSol.toNumber());
+    assert.equal(after.unclaimedWinnings.toNumber(), 0);
+  });
+
+  it("Closes once every claim is settled", async () => {
+    const treasurySnow = await createTokenAccount(env, game.mint, env.payer.publicKey);
+
+    await game.close(treasurySnow);
+    assert.isNull(await env.context.banksClient.getAccount(game.address));
+  });
+});
@@ -0,0 +1,53 @@
+import { assert } from "chai";
+import { SystemProgram, Transaction } from "@solana/web3.js";
+import { Env, Game, defaultParams, expectError, nextSlot, startEnv } from "./helpers";
+
+describe("⚖️ Snowball Effect: Pot Sync", () => {
+  const pot = 5_000_000_000;
+
+  let env: Env;
+  let game: Game;
+
+  const syncPot = async () => {
+    await nextSlot(env.context);
+    return env.program.methods.syncPot().accounts({ gameState: game.address }).rpc();
+  };
+
+  before(async () => {
+    env = await startEnv();
+    game = await Game.create(env, defaultParams());
+    await game.deposit(pot);
+  });
+
+  it("Credits lamports sent straight to the game PDA to the pot", async () => {
+    const donation = 1_500_000_000;
+    await env.provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({ fromPubkey: env.payer.publicKey, toPubkey: game.address, lamports: donation })
+      )
+    );
+
+    await syncPot();
+
+    assert.equal((await game.state()).potBalanceSol.toNumber(), pot + donation);
+  });
+
+  it("Leaves a matching pot alone", async () => {
+    const before = (await game.state()).potBalanceSol.toNumber();
+    await game.deposit(1);
+    await syncPot();
+
+    assert.equal((await game.state()).potBalanceSol.toNumber(), before + 1);
+  });
+
+  it("Refuses to sync or resolve when the PDA holds less than it owes", async () => {
+    const account = await env.context.banksClient.getAccount(game.address);
+    env.context.setAccount(game.address, { ...account, lamports: Number(account.lamports) - 1_000_000_000 });
+
+    await expectError(syncPot(), "PotMismatch");
+
+    await game.push(await game.player());
+    await game.expire();
+    await expectError(game.resolve(), "PotMismatch");
+  });
+});
@@ -0,0 +1,72 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Env, Game, Player, defaultParams, expectError, now, startEnv, warpTo } from "./helpers";
+
+describe("⏱️ Snowball Effect: Anti-Snipe Timer", () => {
+  const params = defaultParams({
+    timerExtension: new anchor.BN(5),
+    timerCap: new anchor.BN(60),
+    snipeWindow: new anchor.BN(10),
+    snipeMinRemaining: new anchor.BN(20),
+    maxRoundLength: new anchor.BN(120),
+  });
+
+  let env: Env;
+  let game: Game;
+  let player: Player;
+  let start: number;
+
+  const timerEnd = async () => (await game.state()).timerEndTimestamp.toNumber();
+
+  before(async () => {
+    env = await startEnv();
+    start = await now(env.context);
+    game = await Game.create(env, params);
+    player = await game.player();
+  });
+
+  it("Adds the per-push extension to the remaining time", async () => {
+    await warpTo(env.context, start + 10);
+    await game.push(player);
+
+    // 50s left + 5s extension.
+    assert.equal(await timerEnd(), start + 65);
+  });
+
+  it("Caps the remaining time after a push", async () => {
+    await warpTo(env.context, start + 11);
+    await game.push(player);
+    assert.equal(await timerEnd(), start + 70);
+
+    // 58s left + 5s would exceed the 60s cap.
+    await warpTo(env.context, start + 12);
+    await game.push(player);
+    assert.equal(await timerEnd(), start + 72);
+  });
+
+  it("Leaves the minimum remaining time after a last-seconds push", async () => {
+    await warpTo(env.context, start + 65);
+    await game.push(player);
+
+    // 7s left is inside the 10s window, so the push leaves 20s.
+    assert.equal(await timerEnd(), start + 85);
+  });
+
+  it("Never extends the timer past the maximum round length", async () => {
+    await warpTo(env.context, start + 80);
+    await game.push(player);
+    await warpTo(env.context, start + 95);
+    await game.push(player);
+    assert.equal(await timerEnd(), start + 115);
+
+    await warpTo(env.context, start + 110);
+    await game.push(player);
+    assert.equal(await timerEnd(), start + 120);
+  });
+
+  it("Rejects pushes once the maximum round length is reached", async () => {
+    await warpTo(env.context, start + 120);
+
+    await expectError(game.push(player), "RoundLengthExceeded");
+  });
+});
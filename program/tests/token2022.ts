@@ -0,0 +1,49 @@
+import { assert } from "chai";
+import {
+  Env,
+  Game,
+  Player,
+  createMint,
+  createTokenAccount,
+  defaultParams,
+  startEnv,
+  tokenBalance,
+} from "./helpers";
+
+describe("🪙 Snowball Effect: Token-2022 SNOW", () => {
+  const feeBps = 100;
+  const params = defaultParams();
+
+  let env: Env;
+  let game: Game;
+  let player: Player;
+
+  before(async () => {
+    env = await startEnv();
+    // 1% transfer fee, uncapped.
+    game = await Game.create(env, params, await createMint(env, feeBps));
+    player = await game.player(10_000_000_000);
+  });
+
+  it("Credits the round with the amount received after transfer fees", async () => {
+    await game.push(player);
+
+    const received = params.pushCost.muln(10_000 - feeBps).divn(10_000);
+    const state = await game.state();
+    assert.equal(state.snowCollected.toString(), received.toString());
+    assert.equal((await tokenBalance(env, game.mint, game.vault)).toString(), received.toString());
+  });
+
+  it("Harvests withheld fees so the vault can be closed", async () => {
+    const snow = await tokenBalance(env, game.mint, game.vault);
+    const treasurySnow = await createTokenAccount(env, game.mint, env.payer.publicKey);
+    await game.expire();
+    await game.resolve();
+
+    await game.close(treasurySnow);
+
+    const fee = (snow * BigInt(feeBps)) / BigInt(10_000);
+    assert.equal((await tokenBalance(env, game.mint, treasurySnow)).toString(), (snow - fee).toString());
+    assert.isNull(await env.context.banksClient.getAccount(game.vault));
+  });
+});
@@ -0,0 +1,81 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import {
+  Env,
+  Game,
+  Player,
+  SnowMint,
+  createMint,
+  createTokenAccount,
+  defaultParams,
+  expectError,
+  startEnv,
+  tokenBalance,
+} from "./helpers";
+
+describe("🔒 Snowball Effect: Vault Validation", () => {
+  const params = defaultParams();
+
+  let env: Env;
+  let game: Game;
+  let fakeMint: SnowMint;
+  let player: Player;
+
+  before(async () => {
+    env = await startEnv();
+    game = await Game.create(env, params);
+    fakeMint = await createMint(env);
+    player = await game.player();
+  });
+
+  it("Rejects a spoofed vault owned by the player", async () => {
+    const spoofedVault = await createTokenAccount(env, game.mint, player.keypair.publicKey);
+
+    await expectError(
+      env.program.methods
+        .pushBall()
+        .accounts(await game.pushAccounts(player, null, spoofedVault))
+        .signers([player.keypair])
+        .rpc(),
+      "ConstraintSeeds"
+    );
+  });
+
+  it("Rejects paying with a token account of another mint", async () => {
+    const fakeSnow = await createTokenAccount(env, fakeMint, player.keypair.publicKey, 10_000_000_000);
+
+    await expectError(game.push({ ...player, snowAccount: fakeSnow }), "ConstraintTokenMint");
+  });
+
+  it("Rejects paying from a token account the player does not own", async () => {
+    const othersSnow = await createTokenAccount(env, game.mint, env.payer.publicKey, 10_000_000_000);
+
+    await expectError(game.push({ ...player, snowAccount: othersSnow }), "ConstraintTokenOwner");
+  });
+
+  it("Rejects withdrawals into a token account of another mint", async () => {
+    const managerFake = await createTokenAccount(env, fakeMint, env.payer.publicKey);
+
+    await expectError(
+      env.program.methods
+        .withdrawSnowForSwap(new anchor.BN(1))
+        .accounts({
+          gameState: game.address,
+          config: game.config,
+          authority: env.payer.publicKey,
+          gameSnowVault: game.vault,
+          snowMint: game.mint.address,
+          managerSnowAccount: managerFake,
+          tokenProgram: game.mint.tokenProgram,
+        })
+        .rpc(),
+      "ConstraintTokenMint"
+    );
+  });
+
+  it("Accepts a push into the game vault", async () => {
+    await game.push(player);
+
+    assert.equal((await tokenBalance(env, game.mint, game.vault)).toString(), params.pushCost.toString());
+  });
+});
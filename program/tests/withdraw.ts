@@ -0,0 +1,71 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Env, Game, createTokenAccount, defaultParams, expectError, nextSlot, startEnv, tokenBalance, warpTo } from "./helpers";
+
+describe("🚰 Snowball Effect: Withdrawal Caps", () => {
+  const snow = 1_000_000_000;
+  const params = defaultParams({
+    maxWithdrawPerRound: new anchor.BN(3 * snow),
+    withdrawWindow: new anchor.BN(60 * 60),
+    maxWithdrawPerWindow: new anchor.BN(2 * snow),
+    minVaultReserve: new anchor.BN(snow),
+  });
+
+  let env: Env;
+  let game: Game;
+  let managerSnow: anchor.web3.PublicKey;
+
+  const withdraw = async (amount: number) => {
+    await nextSlot(env.context);
+    return env.program.methods
+      .withdrawSnowForSwap(new anchor.BN(amount))
+      .accounts({
+        gameState: game.address,
+        config: game.config,
+        authority: env.payer.publicKey,
+        gameSnowVault: game.vault,
+        snowMint: game.mint.address,
+        managerSnowAccount: managerSnow,
+        tokenProgram: game.mint.tokenProgram,
+      })
+      .rpc();
+  };
+
+  const nextWindow = async () => {
+    const state = await game.state();
+    await warpTo(env.context, state.withdrawWindowStart.toNumber() + params.withdrawWindow.toNumber());
+  };
+
+  before(async () => {
+    env = await startEnv();
+    game = await Game.create(env, params);
+    managerSnow = await createTokenAccount(env, game.mint, env.payer.publicKey);
+    await game.pushMany(await game.player(), 5);
+  });
+
+  it("Caps what leaves the vault within one window", async () => {
+    await withdraw(2 * snow);
+
+    await expectError(withdraw(1), "WithdrawLimitExceeded");
+  });
+
+  it("Caps what leaves the vault within one round", async () => {
+    await nextWindow();
+    await withdraw(snow);
+
+    await expectError(withdraw(1), "WithdrawLimitExceeded");
+    assert.equal((await game.state()).roundSnowWithdrawn.toNumber(), 3 * snow);
+  });
+
+  it("Keeps the minimum reserve in the vault", async () => {
+    await game.expire();
+    await game.resolve(true);
+    await nextWindow();
+
+    await expectError(withdraw(snow + 1), "WithdrawLimitExceeded");
+    await withdraw(snow);
+
+    assert.equal((await tokenBalance(env, game.mint, game.vault)).toString(), String(snow));
+    assert.equal((await tokenBalance(env, game.mint, managerSnow)).toString(), String(4 * snow));
+  });
+});
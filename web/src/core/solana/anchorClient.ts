@@ -1,27 +1,121 @@
 import { Connection, PublicKey, TransactionInstruction, Transaction, SystemProgram, ComputeBudgetProgram } from "@solana/web3.js";
 import { WalletContextState } from "@solana/wallet-adapter-react";
 import { sha256 } from "js-sha256";
-import { 
-    TOKEN_PROGRAM_ID, 
-    ASSOCIATED_TOKEN_PROGRAM_ID,
-    getAssociatedTokenAddress, 
-    createAssociatedTokenAccountInstruction 
-} from "@solana/spl-token";
+import { getAssociatedTokenAddress } from "@solana/spl-token";
 
 // --- CONSTANTS ---
 // PRIORITY: Environment Variables (Mainnet) -> Hardcoded Fallbacks (Devnet)
 const PROGRAM_ID_STRING = process.env.NEXT_PUBLIC_PROGRAM_ID || "HFn2E5EV2MyUw42n2ZENx8btzeKBeQ9aDyo9GRQQ9ebs";
 const SNOW_MINT_STRING = process.env.NEXT_PUBLIC_SNOW_MINT || "EDauNNfEp1QvnBamXHnMd8C8H24hXfEURW8T6DDkpump";
+// One deployment runs many games, each keyed by its creator-chosen id.
+const GAME_ID = BigInt(process.env.NEXT_PUBLIC_GAME_ID || "1");
+const SEASON_ID = Number(process.env.NEXT_PUBLIC_SEASON_ID || "0");
+
+// --- SEEDS (must match programs/snowball/src/lib.rs) ---
+const GAME_SEED = "game_v4";
+const CONFIG_SEED = "config";
+const VAULT_SEED = "snow_vault";
+const ROUND_SEED = "round";
+const WINNINGS_SEED = "winnings";
+const PLAYER_ROUND_SEED = "player_round";
+const REFERRAL_SEED = "referral";
+const PLAYER_STATS_SEED = "player_stats";
+
+// GameState accounts of any other layout version are refused by the program.
+const GAME_STATE_VERSION = 2;
+
+export type PriceCurve =
+    | { fixed: {} }
+    | { linear: { increment: bigint } }
+    | { exponential: { growthBps: number } };
+
+// Mirrors `GameParams` in the program, field for field.
+export interface GameParams {
+    pushCost: bigint;
+    roundDuration: bigint;
+    winnerSharesBps: [number, number, number];
+    creatorShareBps: number;
+    dividendShareBps: number;
+    carryoverBps: number;
+    keeperBountyBps: number;
+    claimExpiry: bigint;
+    maxWithdrawPerRound: bigint;
+    withdrawWindow: bigint;
+    maxWithdrawPerWindow: bigint;
+    minVaultReserve: bigint;
+    priceCurve: PriceCurve;
+    burnBps: number;
+    referralBps: number;
+    solPushPrice: bigint;
+    repeatWinnerSlots: boolean;
+    timerExtension: bigint;
+    timerCap: bigint;
+    snipeWindow: bigint;
+    snipeMinRemaining: bigint;
+    maxRoundLength: bigint;
+}
+
+// --- BORSH ENCODING (little endian, as Anchor serializes instruction args) ---
+const encodeU8 = (value: number) => Buffer.from([value]);
+const encodeU16 = (value: number) => {
+    const buf = Buffer.alloc(2);
+    buf.writeUInt16LE(value);
+    return buf;
+};
+const encodeU64 = (value: bigint) => {
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64LE(value);
+    return buf;
+};
+const encodeI64 = (value: bigint) => {
+    const buf = Buffer.alloc(8);
+    buf.writeBigInt64LE(value);
+    return buf;
+};
+
+const encodePriceCurve = (curve: PriceCurve) => {
+    if ("linear" in curve) return Buffer.concat([encodeU8(1), encodeU64(curve.linear.increment)]);
+    if ("exponential" in curve) return Buffer.concat([encodeU8(2), encodeU16(curve.exponential.growthBps)]);
+    return encodeU8(0);
+};
+
+const encodeGameParams = (params: GameParams) =>
+    Buffer.concat([
+        encodeU64(params.pushCost),
+        encodeI64(params.roundDuration),
+        ...params.winnerSharesBps.map(encodeU16),
+        encodeU16(params.creatorShareBps),
+        encodeU16(params.dividendShareBps),
+        encodeU16(params.carryoverBps),
+        encodeU16(params.keeperBountyBps),
+        encodeI64(params.claimExpiry),
+        encodeU64(params.maxWithdrawPerRound),
+        encodeI64(params.withdrawWindow),
+        encodeU64(params.maxWithdrawPerWindow),
+        encodeU64(params.minVaultReserve),
+        encodePriceCurve(params.priceCurve),
+        encodeU16(params.burnBps),
+        encodeU16(params.referralBps),
+        encodeU64(params.solPushPrice),
+        encodeU8(params.repeatWinnerSlots ? 1 : 0),
+        encodeI64(params.timerExtension),
+        encodeI64(params.timerCap),
+        encodeI64(params.snipeWindow),
+        encodeI64(params.snipeMinRemaining),
+        encodeI64(params.maxRoundLength),
+    ]);
 
 export class SnowballClient {
     connection: Connection;
     wallet: WalletContextState;
     programId: PublicKey;
+    gameId: bigint;
 
-    constructor(connection: Connection, wallet: WalletContextState) {
+    constructor(connection: Connection, wallet: WalletContextState, gameId: bigint = GAME_ID) {
         this.connection = connection;
         this.wallet = wallet;
         this.programId = new PublicKey(PROGRAM_ID_STRING);
+        this.gameId = gameId;
     }
 
     // --- HELPER: Compute Discriminator (Function Signature) ---
@@ -31,21 +125,51 @@ export class SnowballClient {
         return Buffer.from(hash.slice(0, 8));
     }
 
-    getGameStatePDA(): PublicKey {
+    findPDA(...seeds: (string | Buffer | PublicKey)[]): PublicKey {
         const [pda] = PublicKey.findProgramAddressSync(
-            [new TextEncoder().encode("game_v4")],
+            seeds.map((seed) =>
+                typeof seed === "string" ? Buffer.from(seed) : seed instanceof PublicKey ? seed.toBuffer() : seed
+            ),
             this.programId
         );
         return pda;
     }
 
+    getGameStatePDA(): PublicKey {
+        return this.findPDA(GAME_SEED, encodeU64(this.gameId));
+    }
+
+    getConfigPDA(gameStatePDA: PublicKey): PublicKey {
+        return this.findPDA(CONFIG_SEED, gameStatePDA);
+    }
+
+    getVaultPDA(gameStatePDA: PublicKey): PublicKey {
+        return this.findPDA(VAULT_SEED, gameStatePDA);
+    }
+
+    getRoundRecordPDA(gameStatePDA: PublicKey, seasonId: number, roundNumber: number): PublicKey {
+        return this.findPDA(ROUND_SEED, gameStatePDA, encodeU16(seasonId), encodeU64(BigInt(roundNumber)));
+    }
+
+    getReferralPDA(gameStatePDA: PublicKey, player: PublicKey): PublicKey {
+        return this.findPDA(REFERRAL_SEED, gameStatePDA, player);
+    }
+
+    // SNOW may be an SPL Token or a Token-2022 mint; the owner tells which.
+    async getTokenProgram(mint: PublicKey): Promise<PublicKey> {
+        const mintInfo = await this.connection.getAccountInfo(mint);
+        if (!mintInfo) throw new Error("SNOW mint not found");
+        return mintInfo.owner;
+    }
+
     // --- MAIN FUNCTIONS ---
 
-    async initializeGame() {
+    async initializeGame(params: GameParams, liquidityManager?: PublicKey, seasonId: number = SEASON_ID) {
         if (!this.wallet.publicKey || !this.wallet.signTransaction) throw new Error("Wallet not connected");
 
         const gameStatePDA = this.getGameStatePDA();
-        console.log("Initializing Game PDA (v3):", gameStatePDA.toBase58());
+        const mint = new PublicKey(SNOW_MINT_STRING);
+        console.log(`Initializing Game ${this.gameId} PDA:`, gameStatePDA.toBase58());
 
         // 1. Calculate Discriminator for 'initialize_game'
         const discriminator = this.getDiscriminator("global", "initialize_game");
@@ -54,43 +178,51 @@ export class SnowballClient {
         const ix = new TransactionInstruction({
             keys: [
                 { pubkey: gameStatePDA, isSigner: false, isWritable: true },
+                { pubkey: this.getConfigPDA(gameStatePDA), isSigner: false, isWritable: true },
+                { pubkey: this.getVaultPDA(gameStatePDA), isSigner: false, isWritable: true },
+                { pubkey: mint, isSigner: false, isWritable: false },
+                { pubkey: this.getRoundRecordPDA(gameStatePDA, seasonId, 1), isSigner: false, isWritable: false },
                 { pubkey: this.wallet.publicKey, isSigner: true, isWritable: true },
+                { pubkey: await this.getTokenProgram(mint), isSigner: false, isWritable: false },
                 { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }, // System Program
             ],
             programId: this.programId,
-            data: discriminator,
+            data: Buffer.concat([
+                discriminator,
+                encodeU64(this.gameId),
+                encodeU16(seasonId),
+                (liquidityManager ?? this.wallet.publicKey).toBuffer(),
+                encodeGameParams(params),
+            ]),
         });
 
         // 3. Send Transaction
         return await this.sendAndConfirm([ix]);
     }
 
-    async pushSnowball() {
+    // `referrer` is only recorded on the player's first push in this game;
+    // after that the referrer already linked to the player is passed.
+    async pushSnowball(referrer?: PublicKey) {
         if (!this.wallet.publicKey) throw new Error("Wallet not connected");
 
+        const user = this.wallet.publicKey;
         const gameStatePDA = this.getGameStatePDA();
+        const gameState = await this.fetchGameState();
+        if (!gameState) throw new Error("Game not found");
         const mint = new PublicKey(SNOW_MINT_STRING);
-        
-        // Calculate ATAs
-        const userSnowAccount = await getAssociatedTokenAddress(mint, this.wallet.publicKey);
-        const gameSnowVault = await getAssociatedTokenAddress(mint, gameStatePDA, true);
-
-        const instructions: TransactionInstruction[] = [];
-
-        // --- AUTO-INIT VAULT CHECK ---
-        const vaultInfo = await this.connection.getAccountInfo(gameSnowVault);
-        if (!vaultInfo) {
-            console.log("Game Vault not found. Creating it now...");
-            const createVaultIx = createAssociatedTokenAccountInstruction(
-                this.wallet.publicKey, // Payer
-                gameSnowVault,         // Associated Token Account
-                gameStatePDA,          // Owner (The Game PDA)
-                mint                   // Mint
-            );
-            instructions.push(createVaultIx);
-        }
+        const tokenProgram = await this.getTokenProgram(mint);
 
-        console.log("Pushing on v3...");
+        const userSnowAccount = await getAssociatedTokenAddress(mint, user, false, tokenProgram);
+        const referral = this.getReferralPDA(gameStatePDA, user);
+        const referralInfo = await this.connection.getAccountInfo(referral);
+        // Referral: discriminator (8), game (32), player (32), bump (1), referrer Option<Pubkey>
+        const linkedReferrer =
+            referralInfo && referralInfo.data[73] === 1 ? new PublicKey(referralInfo.data.slice(74, 106)) : undefined;
+        const referrerWallet = referralInfo ? linkedReferrer : referrer;
+        // Anchor reads the program id in an optional account's slot as `None`.
+        const referrerAccount = referrerWallet ? this.getReferralPDA(gameStatePDA, referrerWallet) : this.programId;
+
+        console.log(`Pushing on game ${this.gameId}...`);
 
         // 1. Calculate Discriminator for 'push_ball'
         const discriminator = this.getDiscriminator("global", "push_ball");
@@ -99,25 +231,42 @@ export class SnowballClient {
         const pushIx = new TransactionInstruction({
             keys: [
                 { pubkey: gameStatePDA, isSigner: false, isWritable: true },
-                { pubkey: this.wallet.publicKey, isSigner: true, isWritable: true },
+                { pubkey: this.getConfigPDA(gameStatePDA), isSigner: false, isWritable: false },
+                { pubkey: user, isSigner: true, isWritable: true },
                 { pubkey: userSnowAccount, isSigner: false, isWritable: true },
-                { pubkey: gameSnowVault, isSigner: false, isWritable: true },
-                { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+                { pubkey: this.getVaultPDA(gameStatePDA), isSigner: false, isWritable: true },
+                { pubkey: mint, isSigner: false, isWritable: true },
+                { pubkey: this.findPDA(WINNINGS_SEED, gameStatePDA, user), isSigner: false, isWritable: true },
+                {
+                    pubkey: this.findPDA(
+                        PLAYER_ROUND_SEED,
+                        gameStatePDA,
+                        encodeU16(gameState.seasonId),
+                        encodeU64(BigInt(gameState.roundNumber)),
+                        user
+                    ),
+                    isSigner: false,
+                    isWritable: true,
+                },
+                { pubkey: referral, isSigner: false, isWritable: true },
+                { pubkey: this.findPDA(PLAYER_STATS_SEED, gameStatePDA, user), isSigner: false, isWritable: true },
+                { pubkey: referrerAccount, isSigner: false, isWritable: !!referrerWallet },
+                { pubkey: tokenProgram, isSigner: false, isWritable: false },
+                { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
             ],
             programId: this.programId,
             data: discriminator,
         });
-        instructions.push(pushIx);
 
         // 3. Send Transaction
-        return await this.sendAndConfirm(instructions);
+        return await this.sendAndConfirm([pushIx]);
     }
 
     async resetGame() {
         if (!this.wallet.publicKey) throw new Error("Wallet not connected");
 
         const gameStatePDA = this.getGameStatePDA();
-        console.log("Resetting Game PDA (v3)...");
+        console.log(`Resetting Game ${this.gameId} PDA...`);
 
         // 1. Calculate Discriminator for 'reset_game'
         const discriminator = this.getDiscriminator("global", "reset_game");
@@ -126,6 +275,7 @@ export class SnowballClient {
         const ix = new TransactionInstruction({
             keys: [
                 { pubkey: gameStatePDA, isSigner: false, isWritable: true },
+                { pubkey: this.getConfigPDA(gameStatePDA), isSigner: false, isWritable: true },
                 { pubkey: this.wallet.publicKey, isSigner: true, isWritable: true }, // Authority
             ],
             programId: this.programId,
@@ -193,21 +343,43 @@ export class SnowballClient {
             // Create view directly from the buffer with correct offset/length
             const view = new DataView(rawData.buffer, rawData.byteOffset, rawData.byteLength);
 
-            // Offsets based on struct:
-            // discriminator (8)
-            // is_active (1)
-            // round_number (8) -> offset 9
-            // timer_end (8) -> offset 17
-            // snow_collected (8) -> offset 25
-            // pot_balance (8) -> offset 33
-            
-            const isActive = view.getUint8(8) === 1;
-            const timerEndTimestamp = Number(view.getBigInt64(17, true));
-            const snowCollected = Number(view.getBigUint64(25, true)) / 1_000_000;
-            const potBalanceSol = Number(view.getBigUint64(33, true)) / 1_000_000_000;
+            // Fields are read in GameState order, after the 8-byte discriminator.
+            let offset = 8;
+            const readU8 = () => view.getUint8(offset++);
+            const readU16 = () => {
+                const value = view.getUint16(offset, true);
+                offset += 2;
+                return value;
+            };
+            const readU64 = () => {
+                const value = view.getBigUint64(offset, true);
+                offset += 8;
+                return value;
+            };
+            const readI64 = () => {
+                const value = view.getBigInt64(offset, true);
+                offset += 8;
+                return value;
+            };
+
+            const version = readU8();
+            if (version !== GAME_STATE_VERSION) {
+                console.log(`Game state is at layout version ${version}, expected ${GAME_STATE_VERSION}`);
+                return null;
+            }
+            readU64(); // game_id
+            const seasonId = readU16();
+            offset += 1 + 1 + 32; // bump, vault_bump, snow_mint
+            const isActive = readU8() === 1;
+            const roundNumber = Number(readU64());
+            readI64(); // round_start_timestamp
+            const timerEndTimestamp = Number(readI64());
+            readI64(); // round_deadline
+            const snowCollected = Number(readU64()) / 1_000_000;
+            const potBalanceSol = Number(readU64()) / 1_000_000_000;
+            offset += 8 * 4; // unclaimed_winnings, unclaimed_dividends, next_round_seed, round_seed
 
             // Dynamic Vector Reading for last_pushers
-            let offset = 41;
             const vecLen = view.getUint32(offset, true);
             offset += 4; // Skip length bytes
 
@@ -220,14 +392,15 @@ export class SnowballClient {
             }
 
             // NOW we are at the correct position for pushCount
-            const pushCount = Number(view.getBigUint64(offset, true));
+            const pushCount = Number(readU64());
 
             console.log("Fetched Game State:", { isActive, pushCount, lastPushers });
 
             return {
                 isActive,
-                roundNumber: 1, 
-                timerEndTimestamp, 
+                seasonId,
+                roundNumber,
+                timerEndTimestamp,
                 snowCollected,
                 potBalanceSol,
                 pushCount,
@@ -238,4 +411,4 @@ export class SnowballClient {
             return null;
         }
     }
-}
\ No newline at end of file
+}
@@ -1,167 +1,5467 @@
 export type Snowball = {
   "version": "0.1.0",
   "name": "snowball",
-  "address": "6PWi6MVo7g3SkSM7wmUi341yo2EAA1WxYKYYyEiEVsxz",
   "instructions": [
     {
       "name": "initializeGame",
       "accounts": [
-        { "name": "gameState", "isMut": true, "isSigner": false },
-        { "name": "authority", "isMut": true, "isSigner": true },
-        { "name": "systemProgram", "isMut": false, "isSigner": false }
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "snowMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "firstRoundRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "yet, so a closed game can only reopen under a new `season_id`."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "seasonId",
+          "type": "u16"
+        },
+        {
+          "name": "liquidityManager",
+          "type": "publicKey"
+        },
+        {
+          "name": "params",
+          "type": {
+            "defined": "GameParams"
+          }
+        }
+      ]
     },
     {
       "name": "pushBall",
       "accounts": [
-        { "name": "gameState", "isMut": true, "isSigner": false },
-        { "name": "user", "isMut": true, "isSigner": true },
-        { "name": "userSnowAccount", "isMut": true, "isSigner": false },
-        { "name": "gameSnowVault", "isMut": true, "isSigner": false },
-        { "name": "tokenProgram", "isMut": false, "isSigner": false }
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userSnowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "snowMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnings",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerRound",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The referrer's own Referral account. Sets the referrer on the first",
+            "push and is credited on every push after that. Optional for players",
+            "without a referrer; required once one is linked."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
       "args": []
     },
     {
-      "name": "depositSolPot",
+      "name": "pushMany",
+      "docs": [
+        "Buys `count` pushes with a single SNOW transfer. The timer moves once",
+        "and the pusher takes one winner slot unless `repeat_winner_slots` is set."
+      ],
       "accounts": [
-        { "name": "gameState", "isMut": true, "isSigner": false },
-        { "name": "authority", "isMut": true, "isSigner": true },
-        { "name": "systemProgram", "isMut": false, "isSigner": false }
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userSnowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "snowMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnings",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerRound",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The referrer's own Referral account. Sets the referrer on the first",
+            "push and is credited on every push after that. Optional for players",
+            "without a referrer; required once one is linked."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "args": [{ "name": "amount", "type": "u64" }]
+      "args": [
+        {
+          "name": "count",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "resolveRound",
+      "name": "quotePush",
+      "docs": [
+        "Returns the SNOW price of the next push in the current round."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "u64"
+    },
+    {
+      "name": "pushBallSol",
+      "docs": [
+        "Pushes for a flat lamport price instead of SNOW. The lamports go",
+        "straight into the pot; there is no burn or referral share."
+      ],
       "accounts": [
-        { "name": "gameState", "isMut": true, "isSigner": false },
-        { "name": "authority", "isMut": false, "isSigner": true }
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "winnings",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerRound",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
       "args": []
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "gameState", 
-      "type": { "kind": "struct", "fields": [
-          { "name": "isActive", "type": "bool" },
-          { "name": "roundNumber", "type": "u64" },
-          { "name": "timerEndTimestamp", "type": "i64" },
-          { "name": "snowCollected", "type": "u64" },
-          { "name": "potBalanceSol", "type": "u64" },
-          { "name": "lastPushers", "type": { "vec": "publicKey" } },
-          { "name": "pushCount", "type": "u64" },
-          { "name": "authority", "type": "publicKey" }
-      ]}
-    }
-  ],
-  "types": [
-      {
-        "name": "GameState",
-        "type": {
-          "kind": "struct",
-          "fields": [
-            { "name": "isActive", "type": "bool" },
-            { "name": "roundNumber", "type": "u64" },
-            { "name": "timerEndTimestamp", "type": "i64" },
-            { "name": "snowCollected", "type": "u64" },
-            { "name": "potBalanceSol", "type": "u64" },
-            { "name": "lastPushers", "type": { "vec": "publicKey" } },
-            { "name": "pushCount", "type": "u64" },
-            { "name": "authority", "type": "publicKey" }
-          ]
+      "name": "updateConfig",
+      "docs": [
+        "Stages new parameters; they take effect when the next round opens."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
         }
-      }
-  ],
-  "errors": [
-    { "code": 6000, "name": "GameNotActive", "msg": "The game is not currently active." },
-    { "code": 6001, "name": "RoundEnded", "msg": "The round has ended." },
-    { "code": 6002, "name": "MathOverflow", "msg": "Math overflow occurred." },
-    { "code": 6003, "name": "RoundStillActive", "msg": "The round is still active, cannot resolve yet." }
-  ]
-};
-
-export const IDL: Snowball = {
-  "version": "0.1.0",
-  "name": "snowball",
-  "address": "6PWi6MVo7g3SkSM7wmUi341yo2EAA1WxYKYYyEiEVsxz",
-  "instructions": [
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "GameParams"
+          }
+        }
+      ]
+    },
     {
-      "name": "initializeGame",
+      "name": "proposeAuthority",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptAuthority",
       "accounts": [
-        { "name": "gameState", "isMut": true, "isSigner": false },
-        { "name": "authority", "isMut": true, "isSigner": true },
-        { "name": "systemProgram", "isMut": false, "isSigner": false }
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
       ],
       "args": []
     },
     {
-      "name": "pushBall",
+      "name": "setLiquidityManager",
+      "docs": [
+        "Schedules a liquidity manager rotation. The new key has to accept it",
+        "once `MANAGER_ROTATION_DELAY` has passed."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newManager",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptLiquidityManager",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newManager",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTreasury",
+      "docs": [
+        "Schedules a treasury rotation. The new key has to accept it once",
+        "`TREASURY_ROTATION_DELAY` has passed."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newTreasury",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptTreasury",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newTreasury",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setGuardian",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newGuardian",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "pauseGame",
+      "docs": [
+        "Freezes pushes, SOL deposits, SNOW withdrawals and round resolution."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unpauseGame",
+      "docs": [
+        "Resumes play, pushing the round timer back by the time spent paused."
+      ],
       "accounts": [
-        { "name": "gameState", "isMut": true, "isSigner": false },
-        { "name": "user", "isMut": true, "isSigner": true },
-        { "name": "userSnowAccount", "isMut": true, "isSigner": false },
-        { "name": "gameSnowVault", "isMut": true, "isSigner": false },
-        { "name": "tokenProgram", "isMut": false, "isSigner": false }
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
       ],
       "args": []
     },
     {
       "name": "depositSolPot",
       "accounts": [
-        { "name": "gameState", "isMut": true, "isSigner": false },
-        { "name": "authority", "isMut": true, "isSigner": true },
-        { "name": "systemProgram", "isMut": false, "isSigner": false }
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawSnowForSwap",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "snowMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerSnowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "convertSnow",
+      "docs": [
+        "Sells SNOW from the game vault on a DEX, with the game PDA as the",
+        "seller, and credits the SOL received to the pot. The venue's accounts",
+        "are passed as remaining accounts in the venue's own order, and",
+        "`min_sol_out` must be set."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "wsolAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required by venues that pay out wrapped SOL; closed after the swap."
+          ]
+        },
+        {
+          "name": "swapProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "args": [{ "name": "amount", "type": "u64" }]
+      "args": [
+        {
+          "name": "venue",
+          "type": {
+            "defined": "SwapVenue"
+          }
+        },
+        {
+          "name": "amountIn",
+          "type": "u64"
+        },
+        {
+          "name": "minSolOut",
+          "type": "u64"
+        }
+      ]
     },
     {
       "name": "resolveRound",
+      "docs": [
+        "Permissionless once the timer has expired. The caller earns the",
+        "keeper bounty and may open the next round in the same instruction."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "roundRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "keeper",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winner1",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Winnings of the last entry of `last_pushers`."
+          ]
+        },
+        {
+          "name": "winner2",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Winnings of the second-to-last entry of `last_pushers`. Must be",
+            "`None` when there is no such entry or it repeats a higher slot."
+          ]
+        },
+        {
+          "name": "winner3",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Winnings of the third-to-last entry of `last_pushers`, with the same",
+            "rule as `winner_2`."
+          ]
+        },
+        {
+          "name": "winner1Stats",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "PlayerStats of the same players, in the same order and with the same",
+            "empty slots."
+          ]
+        },
+        {
+          "name": "winner2Stats",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "winner3Stats",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "restart",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "claimWinnings",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnings",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimDividend",
+      "docs": [
+        "Pays a player's dividend for a resolved round and closes their",
+        "PlayerRound account."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "roundRecord",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerRound",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "roundNumber",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "claimReferralRewards",
+      "docs": [
+        "Pays out the SNOW a referrer has earned from referred pushes."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "snowMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referrerSnowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sweepExpiredWinnings",
+      "docs": [
+        "Moves winnings left unclaimed past `claim_expiry` into the seed of",
+        "the next round."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "winnings",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sweepExpiredDividend",
+      "docs": [
+        "Moves a dividend left unclaimed past `claim_expiry` into the seed of",
+        "the next round and closes the player's PlayerRound account, refunding",
+        "its rent to them."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "roundRecord",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerRound",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "roundNumber",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "sweepExpiredReferral",
+      "docs": [
+        "Releases referral SNOW left unclaimed past `claim_expiry`. The SNOW",
+        "stays in the vault and is no longer held back from withdrawals."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "syncPot",
+      "docs": [
+        "Reconciles `pot_balance_sol` with the lamports actually held by the",
+        "game PDA. Any surplus (e.g. direct transfers) is added to the pot; a",
+        "deficit means the books are wrong and is reported as an error."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateState",
+      "docs": [
+        "Upgrades a version 1 GameState, written before the treasury fields",
+        "existed, to the current layout. The account grows to the current",
+        "size, paid for by the authority, every old field is copied over and",
+        "the treasury starts out as the authority."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "drainLegacy",
+      "docs": [
+        "Moves the SOL and SNOW left in a game PDA of an earlier seed scheme",
+        "(a single `legacy_seed`, such as the original `game_v4`) into this",
+        "game, then closes the legacy accounts. The legacy game must have the",
+        "same authority."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "legacyState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "legacyVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "SNOW account owned by the legacy PDA; closed once emptied."
+          ]
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "snowMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "legacySeed",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "closeGame",
+      "docs": [
+        "Ends a season: sends the SNOW left in the vault and every lamport of",
+        "the game PDA above rent (pot and seed included) to the treasury,",
+        "then closes the vault, config and game accounts and returns their",
+        "rent to the authority. Every winner share, dividend and referral",
+        "reward must have been claimed or swept first."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "snowMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Writable to take harvested Token-2022 transfer fees."
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasurySnowAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Receives the SNOW left in the vault."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "resetGame",
       "accounts": [
-        { "name": "gameState", "isMut": true, "isSigner": false },
-        { "name": "authority", "isMut": false, "isSigner": true }
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        }
       ],
       "args": []
     }
   ],
   "accounts": [
-     {
-      "name": "gameState", 
-      "type": { "kind": "struct", "fields": [
-          { "name": "isActive", "type": "bool" },
-          { "name": "roundNumber", "type": "u64" },
-          { "name": "timerEndTimestamp", "type": "i64" },
-          { "name": "snowCollected", "type": "u64" },
-          { "name": "potBalanceSol", "type": "u64" },
-          { "name": "lastPushers", "type": { "vec": "publicKey" } },
-          { "name": "pushCount", "type": "u64" },
-          { "name": "authority", "type": "publicKey" }
-      ]}
+    {
+      "name": "GameState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "gameId",
+            "type": "u64"
+          },
+          {
+            "name": "seasonId",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "vaultBump",
+            "type": "u8"
+          },
+          {
+            "name": "snowMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "isActive",
+            "type": "bool"
+          },
+          {
+            "name": "roundNumber",
+            "type": "u64"
+          },
+          {
+            "name": "roundStartTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "timerEndTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "roundDeadline",
+            "docs": [
+              "Hard end of the round from `max_round_length`; 0 when unlimited."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "snowCollected",
+            "type": "u64"
+          },
+          {
+            "name": "potBalanceSol",
+            "type": "u64"
+          },
+          {
+            "name": "unclaimedWinnings",
+            "docs": [
+              "Lamports credited to Winnings accounts but not yet claimed."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unclaimedDividends",
+            "docs": [
+              "Lamports reserved for dividends of resolved rounds but not yet claimed."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nextRoundSeed",
+            "docs": [
+              "Carryover set aside by `resolve_round`, moved into the pot when the",
+              "next round opens."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "roundSeed",
+            "docs": [
+              "Seed the current round opened with."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastPushers",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "pushCount",
+            "type": "u64"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "liquidityManager",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "pendingLiquidityManager",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "liquidityManagerEta",
+            "docs": [
+              "Earliest time the pending liquidity manager can accept."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "guardian",
+            "docs": [
+              "May pause the game; only the authority can unpause it."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "isPaused",
+            "type": "bool"
+          },
+          {
+            "name": "pausedAt",
+            "type": "i64"
+          },
+          {
+            "name": "roundSnowWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "roundSolDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "withdrawWindowStart",
+            "type": "i64"
+          },
+          {
+            "name": "windowSnowWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "totalSnowWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "totalSolDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "totalBurned",
+            "docs": [
+              "SNOW burned by pushes over the lifetime of the game."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unclaimedReferralSnow",
+            "docs": [
+              "Referral SNOW held in the vault until referrers claim it."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "Receives the SNOW and SOL left over when the game is closed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingTreasury",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "treasuryEta",
+            "docs": [
+              "Earliest time the pending treasury can accept."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Winnings",
+      "docs": [
+        "Per-player balance of credited but unclaimed winner shares."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "publicKey"
+          },
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "lastCreditedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlayerStats",
+      "docs": [
+        "Lifetime totals of a player in one game, for on-chain leaderboards."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "publicKey"
+          },
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "totalPushes",
+            "type": "u64"
+          },
+          {
+            "name": "snowSpent",
+            "docs": [
+              "SNOW paid for pushes, including any burned part."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "solSpent",
+            "docs": [
+              "Lamports paid through `push_ball_sol`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "roundsPlayed",
+            "type": "u64"
+          },
+          {
+            "name": "wins",
+            "docs": [
+              "Rounds finished in each winner slot, last pusher first."
+            ],
+            "type": {
+              "array": [
+                "u64",
+                3
+              ]
+            }
+          },
+          {
+            "name": "totalSolWon",
+            "docs": [
+              "Lamports credited as winner shares. Counts credits, not payouts:",
+              "shares later returned to the pot by `sweep_expired_winnings` stay",
+              "included."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Referral",
+      "docs": [
+        "Per-player referral link, and the SNOW the player has earned as a",
+        "referrer of others."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "publicKey"
+          },
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "referrer",
+            "docs": [
+              "Set on the player's first push and never changed."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "earned",
+            "type": "u64"
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          },
+          {
+            "name": "lastCreditedAt",
+            "docs": [
+              "Time of the last referral credit, for `sweep_expired_referral`."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlayerRound",
+      "docs": [
+        "A player's pushes in one round, used to pay the round's dividend."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "publicKey"
+          },
+          {
+            "name": "roundNumber",
+            "type": "u64"
+          },
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pushes",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RoundRecord",
+      "docs": [
+        "Immutable summary of a resolved round, written once by `resolve_round`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "publicKey"
+          },
+          {
+            "name": "roundNumber",
+            "type": "u64"
+          },
+          {
+            "name": "winners",
+            "type": {
+              "array": [
+                "publicKey",
+                3
+              ]
+            }
+          },
+          {
+            "name": "winnerPayouts",
+            "type": {
+              "array": [
+                "u64",
+                3
+              ]
+            }
+          },
+          {
+            "name": "creatorPayout",
+            "type": "u64"
+          },
+          {
+            "name": "keeper",
+            "type": "publicKey"
+          },
+          {
+            "name": "keeperBounty",
+            "type": "u64"
+          },
+          {
+            "name": "dividendPool",
+            "type": "u64"
+          },
+          {
+            "name": "dividendPerPush",
+            "type": "u64"
+          },
+          {
+            "name": "seed",
+            "docs": [
+              "Carryover of the previous round this round opened with."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "carryover",
+            "docs": [
+              "Set aside from this round's pot to seed the next one."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pushCount",
+            "type": "u64"
+          },
+          {
+            "name": "snowCollected",
+            "type": "u64"
+          },
+          {
+            "name": "snowWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "solDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "pot",
+            "type": "u64"
+          },
+          {
+            "name": "startedAt",
+            "type": "i64"
+          },
+          {
+            "name": "endedAt",
+            "type": "i64"
+          },
+          {
+            "name": "resolvedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GameConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "publicKey"
+          },
+          {
+            "name": "params",
+            "type": {
+              "defined": "GameParams"
+            }
+          },
+          {
+            "name": "pending",
+            "docs": [
+              "Staged by `update_config`, applied by the next `reset_game`."
+            ],
+            "type": {
+              "option": {
+                "defined": "GameParams"
+              }
+            }
+          }
+        ]
+      }
     }
   ],
   "types": [
-      {
-        "name": "GameState",
-        "type": {
-          "kind": "struct",
-          "fields": [
-            { "name": "isActive", "type": "bool" },
-            { "name": "roundNumber", "type": "u64" },
-            { "name": "timerEndTimestamp", "type": "i64" },
-            { "name": "snowCollected", "type": "u64" },
-            { "name": "potBalanceSol", "type": "u64" },
-            { "name": "lastPushers", "type": { "vec": "publicKey" } },
-            { "name": "pushCount", "type": "u64" },
-            { "name": "authority", "type": "publicKey" }
-          ]
-        }
+    {
+      "name": "GameParams",
+      "docs": [
+        "Tunable game rules. Shares are in basis points and index 0 of",
+        "`winner_shares_bps` is the last pusher. The keeper bounty is taken off",
+        "the top of the pot before the shares are applied."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pushCost",
+            "type": "u64"
+          },
+          {
+            "name": "roundDuration",
+            "type": "i64"
+          },
+          {
+            "name": "winnerSharesBps",
+            "type": {
+              "array": [
+                "u16",
+                3
+              ]
+            }
+          },
+          {
+            "name": "creatorShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "dividendShareBps",
+            "docs": [
+              "Slice of the pot split pro-rata across every push of the round."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "carryoverBps",
+            "docs": [
+              "Slice of the pot held back to seed the next round."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "keeperBountyBps",
+            "type": "u16"
+          },
+          {
+            "name": "claimExpiry",
+            "docs": [
+              "Seconds after which unclaimed winnings may be swept back to the pot."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxWithdrawPerRound",
+            "docs": [
+              "Limits on `withdraw_snow_for_swap`, in SNOW base units."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "withdrawWindow",
+            "type": "i64"
+          },
+          {
+            "name": "maxWithdrawPerWindow",
+            "type": "u64"
+          },
+          {
+            "name": "minVaultReserve",
+            "type": "u64"
+          },
+          {
+            "name": "priceCurve",
+            "type": {
+              "defined": "PriceCurve"
+            }
+          },
+          {
+            "name": "burnBps",
+            "docs": [
+              "Slice of each push burned instead of sent to the vault."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "referralBps",
+            "docs": [
+              "Slice of each referred push owed to the player's referrer."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "solPushPrice",
+            "docs": [
+              "Lamport price of `push_ball_sol`; zero disables SOL pushes."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "repeatWinnerSlots",
+            "docs": [
+              "Lets one wallet hold several winner slots, one per push, including",
+              "from a single `push_many`. Otherwise a push moves the pusher to the",
+              "last slot."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "timerExtension",
+            "docs": [
+              "Seconds a push adds to the remaining time, up to `timer_cap`.",
+              "Zero keeps the hard reset to `round_duration`."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "timerCap",
+            "type": "i64"
+          },
+          {
+            "name": "snipeWindow",
+            "docs": [
+              "A push with less than `snipe_window` seconds left leaves at least",
+              "`snipe_min_remaining` seconds on the clock."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "snipeMinRemaining",
+            "type": "i64"
+          },
+          {
+            "name": "maxRoundLength",
+            "docs": [
+              "Pushes are rejected this many seconds after the round opened;",
+              "zero means no limit."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapVenue",
+      "docs": [
+        "DEX used by `convert_snow` to sell SNOW for SOL."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "PumpFun"
+          },
+          {
+            "name": "RaydiumV4"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceCurve",
+      "docs": [
+        "How the SNOW price of a push grows with the round's `push_count`,",
+        "starting from `GameParams::push_cost`."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Fixed"
+          },
+          {
+            "name": "Linear",
+            "fields": [
+              {
+                "name": "increment",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "Exponential",
+            "fields": [
+              {
+                "name": "growthBps",
+                "type": "u16"
+              }
+            ]
+          }
+        ]
       }
+    }
   ],
-  "errors": [
-    { "code": 6000, "name": "GameNotActive", "msg": "The game is not currently active." },
-    { "code": 6001, "name": "RoundEnded", "msg": "The round has ended." },
-    { "code": 6002, "name": "MathOverflow", "msg": "Math overflow occurred." },
-    { "code": 6003, "name": "RoundStillActive", "msg": "The round is still active, cannot resolve yet." }
+  "events": [
+    {
+      "name": "PushEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pusher",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pushes",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pushCount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "burned",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "received",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "referralShare",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "snowCollected",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalBurned",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timerEndTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SolPushEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pusher",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pushCount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "potBalanceSol",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timerEndTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SolDepositEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "depositor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "potBalanceSol",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SnowWithdrawEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "manager",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "snowCollected",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "roundSnowWithdrawn",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoundEndedEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pushCount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "winners",
+          "type": {
+            "array": [
+              "publicKey",
+              3
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "winnerPayouts",
+          "type": {
+            "array": [
+              "u64",
+              3
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creatorPayout",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "keeper",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "keeperBounty",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "dividendPool",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "carryover",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "rollover",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ConfigUpdateEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "params",
+          "type": {
+            "defined": "GameParams"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WinningsClaimEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DividendClaimEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pushes",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReferralClaimEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WinningsSweepEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "nextRoundSeed",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DividendSweepEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "nextRoundSeed",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReferralSweepEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PotSyncEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendable",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "surplus",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "potBalanceSol",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityProposeEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposed",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityTransferEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previous",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LiquidityManagerProposeEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liquidityManager",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposed",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "eta",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LiquidityManagerTransferEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previous",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liquidityManager",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TreasuryProposeEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposed",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "eta",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TreasuryTransferEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previous",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SnowConvertEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "venue",
+          "type": {
+            "defined": "SwapVenue"
+          },
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "snowIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "solOut",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "potBalanceSol",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GuardianUpdateEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PauseEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pausedBy",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UnpauseEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pausedFor",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "timerEndTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "StateMigrateEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LegacyDrainEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "legacy",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "snow",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "potBalanceSol",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GameCloseEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "snow",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GameResetEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timerEndTimestamp",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "seed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "potBalanceSol",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "GameNotActive",
+      "msg": "ErrActive"
+    },
+    {
+      "code": 6001,
+      "name": "RoundEnded",
+      "msg": "ErrEnded"
+    },
+    {
+      "code": 6002,
+      "name": "MathOverflow",
+      "msg": "ErrOvrflw"
+    },
+    {
+      "code": 6003,
+      "name": "RoundStillActive",
+      "msg": "ErrActive"
+    },
+    {
+      "code": 6004,
+      "name": "Unauthorized",
+      "msg": "ErrAuth"
+    },
+    {
+      "code": 6005,
+      "name": "WinnerMismatch",
+      "msg": "ErrWinner"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidConfig",
+      "msg": "ErrConfig"
+    },
+    {
+      "code": 6007,
+      "name": "NothingToClaim",
+      "msg": "ErrNoClaim"
+    },
+    {
+      "code": 6008,
+      "name": "ClaimNotExpired",
+      "msg": "ErrNotExpired"
+    },
+    {
+      "code": 6009,
+      "name": "PotMismatch",
+      "msg": "ErrPot"
+    },
+    {
+      "code": 6010,
+      "name": "TimelockActive",
+      "msg": "ErrTimelock"
+    },
+    {
+      "code": 6011,
+      "name": "WithdrawLimitExceeded",
+      "msg": "ErrWithdrawLimit"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidSwapAccounts",
+      "msg": "ErrSwapAccounts"
+    },
+    {
+      "code": 6013,
+      "name": "SlippageExceeded",
+      "msg": "ErrSlippage"
+    },
+    {
+      "code": 6014,
+      "name": "GamePaused",
+      "msg": "ErrPaused"
+    },
+    {
+      "code": 6015,
+      "name": "GameNotPaused",
+      "msg": "ErrNotPaused"
+    },
+    {
+      "code": 6016,
+      "name": "RoundLengthExceeded",
+      "msg": "ErrMaxLength"
+    },
+    {
+      "code": 6017,
+      "name": "ReferrerMismatch",
+      "msg": "ErrReferrer"
+    },
+    {
+      "code": 6018,
+      "name": "SolPushDisabled",
+      "msg": "ErrSolPush"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidPushCount",
+      "msg": "ErrPushCount"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidLayout",
+      "msg": "ErrLayout"
+    },
+    {
+      "code": 6021,
+      "name": "UnclaimedWinnings",
+      "msg": "ErrUnclaimed"
+    },
+    {
+      "code": 6022,
+      "name": "SeasonAlreadyPlayed",
+      "msg": "ErrSeasonUsed"
+    }
+  ]
+};
+
+export const IDL: Snowball = {
+  "version": "0.1.0",
+  "name": "snowball",
+  "instructions": [
+    {
+      "name": "initializeGame",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "snowMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "firstRoundRecord",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "yet, so a closed game can only reopen under a new `season_id`."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "seasonId",
+          "type": "u16"
+        },
+        {
+          "name": "liquidityManager",
+          "type": "publicKey"
+        },
+        {
+          "name": "params",
+          "type": {
+            "defined": "GameParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "pushBall",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userSnowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "snowMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnings",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerRound",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The referrer's own Referral account. Sets the referrer on the first",
+            "push and is credited on every push after that. Optional for players",
+            "without a referrer; required once one is linked."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pushMany",
+      "docs": [
+        "Buys `count` pushes with a single SNOW transfer. The timer moves once",
+        "and the pusher takes one winner slot unless `repeat_winner_slots` is set."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userSnowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "snowMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnings",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerRound",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The referrer's own Referral account. Sets the referrer on the first",
+            "push and is credited on every push after that. Optional for players",
+            "without a referrer; required once one is linked."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "count",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "quotePush",
+      "docs": [
+        "Returns the SNOW price of the next push in the current round."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "u64"
+    },
+    {
+      "name": "pushBallSol",
+      "docs": [
+        "Pushes for a flat lamport price instead of SNOW. The lamports go",
+        "straight into the pot; there is no burn or referral share."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "winnings",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerRound",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateConfig",
+      "docs": [
+        "Stages new parameters; they take effect when the next round opens."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "GameParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAuthority",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptAuthority",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setLiquidityManager",
+      "docs": [
+        "Schedules a liquidity manager rotation. The new key has to accept it",
+        "once `MANAGER_ROTATION_DELAY` has passed."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newManager",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptLiquidityManager",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newManager",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTreasury",
+      "docs": [
+        "Schedules a treasury rotation. The new key has to accept it once",
+        "`TREASURY_ROTATION_DELAY` has passed."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newTreasury",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptTreasury",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newTreasury",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setGuardian",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newGuardian",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "pauseGame",
+      "docs": [
+        "Freezes pushes, SOL deposits, SNOW withdrawals and round resolution."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unpauseGame",
+      "docs": [
+        "Resumes play, pushing the round timer back by the time spent paused."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "depositSolPot",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawSnowForSwap",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "snowMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerSnowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "convertSnow",
+      "docs": [
+        "Sells SNOW from the game vault on a DEX, with the game PDA as the",
+        "seller, and credits the SOL received to the pot. The venue's accounts",
+        "are passed as remaining accounts in the venue's own order, and",
+        "`min_sol_out` must be set."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "wsolAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required by venues that pay out wrapped SOL; closed after the swap."
+          ]
+        },
+        {
+          "name": "swapProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "venue",
+          "type": {
+            "defined": "SwapVenue"
+          }
+        },
+        {
+          "name": "amountIn",
+          "type": "u64"
+        },
+        {
+          "name": "minSolOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "resolveRound",
+      "docs": [
+        "Permissionless once the timer has expired. The caller earns the",
+        "keeper bounty and may open the next round in the same instruction."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "roundRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "keeper",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winner1",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Winnings of the last entry of `last_pushers`."
+          ]
+        },
+        {
+          "name": "winner2",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Winnings of the second-to-last entry of `last_pushers`. Must be",
+            "`None` when there is no such entry or it repeats a higher slot."
+          ]
+        },
+        {
+          "name": "winner3",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Winnings of the third-to-last entry of `last_pushers`, with the same",
+            "rule as `winner_2`."
+          ]
+        },
+        {
+          "name": "winner1Stats",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "PlayerStats of the same players, in the same order and with the same",
+            "empty slots."
+          ]
+        },
+        {
+          "name": "winner2Stats",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "winner3Stats",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "restart",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "claimWinnings",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnings",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimDividend",
+      "docs": [
+        "Pays a player's dividend for a resolved round and closes their",
+        "PlayerRound account."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "roundRecord",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerRound",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "roundNumber",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "claimReferralRewards",
+      "docs": [
+        "Pays out the SNOW a referrer has earned from referred pushes."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "snowMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referrerSnowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sweepExpiredWinnings",
+      "docs": [
+        "Moves winnings left unclaimed past `claim_expiry` into the seed of",
+        "the next round."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "winnings",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sweepExpiredDividend",
+      "docs": [
+        "Moves a dividend left unclaimed past `claim_expiry` into the seed of",
+        "the next round and closes the player's PlayerRound account, refunding",
+        "its rent to them."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "roundRecord",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerRound",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "roundNumber",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "sweepExpiredReferral",
+      "docs": [
+        "Releases referral SNOW left unclaimed past `claim_expiry`. The SNOW",
+        "stays in the vault and is no longer held back from withdrawals."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "syncPot",
+      "docs": [
+        "Reconciles `pot_balance_sol` with the lamports actually held by the",
+        "game PDA. Any surplus (e.g. direct transfers) is added to the pot; a",
+        "deficit means the books are wrong and is reported as an error."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateState",
+      "docs": [
+        "Upgrades a version 1 GameState, written before the treasury fields",
+        "existed, to the current layout. The account grows to the current",
+        "size, paid for by the authority, every old field is copied over and",
+        "the treasury starts out as the authority."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "drainLegacy",
+      "docs": [
+        "Moves the SOL and SNOW left in a game PDA of an earlier seed scheme",
+        "(a single `legacy_seed`, such as the original `game_v4`) into this",
+        "game, then closes the legacy accounts. The legacy game must have the",
+        "same authority."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "legacyState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "legacyVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "SNOW account owned by the legacy PDA; closed once emptied."
+          ]
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "snowMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "legacySeed",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "closeGame",
+      "docs": [
+        "Ends a season: sends the SNOW left in the vault and every lamport of",
+        "the game PDA above rent (pot and seed included) to the treasury,",
+        "then closes the vault, config and game accounts and returns their",
+        "rent to the authority. Every winner share, dividend and referral",
+        "reward must have been claimed or swept first."
+      ],
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameSnowVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "snowMint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Writable to take harvested Token-2022 transfer fees."
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasurySnowAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Receives the SNOW left in the vault."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "resetGame",
+      "accounts": [
+        {
+          "name": "gameState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "GameState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "gameId",
+            "type": "u64"
+          },
+          {
+            "name": "seasonId",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "vaultBump",
+            "type": "u8"
+          },
+          {
+            "name": "snowMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "isActive",
+            "type": "bool"
+          },
+          {
+            "name": "roundNumber",
+            "type": "u64"
+          },
+          {
+            "name": "roundStartTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "timerEndTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "roundDeadline",
+            "docs": [
+              "Hard end of the round from `max_round_length`; 0 when unlimited."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "snowCollected",
+            "type": "u64"
+          },
+          {
+            "name": "potBalanceSol",
+            "type": "u64"
+          },
+          {
+            "name": "unclaimedWinnings",
+            "docs": [
+              "Lamports credited to Winnings accounts but not yet claimed."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unclaimedDividends",
+            "docs": [
+              "Lamports reserved for dividends of resolved rounds but not yet claimed."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nextRoundSeed",
+            "docs": [
+              "Carryover set aside by `resolve_round`, moved into the pot when the",
+              "next round opens."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "roundSeed",
+            "docs": [
+              "Seed the current round opened with."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastPushers",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "pushCount",
+            "type": "u64"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "liquidityManager",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "pendingLiquidityManager",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "liquidityManagerEta",
+            "docs": [
+              "Earliest time the pending liquidity manager can accept."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "guardian",
+            "docs": [
+              "May pause the game; only the authority can unpause it."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "isPaused",
+            "type": "bool"
+          },
+          {
+            "name": "pausedAt",
+            "type": "i64"
+          },
+          {
+            "name": "roundSnowWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "roundSolDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "withdrawWindowStart",
+            "type": "i64"
+          },
+          {
+            "name": "windowSnowWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "totalSnowWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "totalSolDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "totalBurned",
+            "docs": [
+              "SNOW burned by pushes over the lifetime of the game."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unclaimedReferralSnow",
+            "docs": [
+              "Referral SNOW held in the vault until referrers claim it."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "Receives the SNOW and SOL left over when the game is closed."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingTreasury",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "treasuryEta",
+            "docs": [
+              "Earliest time the pending treasury can accept."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Winnings",
+      "docs": [
+        "Per-player balance of credited but unclaimed winner shares."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "publicKey"
+          },
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "lastCreditedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlayerStats",
+      "docs": [
+        "Lifetime totals of a player in one game, for on-chain leaderboards."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "publicKey"
+          },
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "totalPushes",
+            "type": "u64"
+          },
+          {
+            "name": "snowSpent",
+            "docs": [
+              "SNOW paid for pushes, including any burned part."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "solSpent",
+            "docs": [
+              "Lamports paid through `push_ball_sol`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "roundsPlayed",
+            "type": "u64"
+          },
+          {
+            "name": "wins",
+            "docs": [
+              "Rounds finished in each winner slot, last pusher first."
+            ],
+            "type": {
+              "array": [
+                "u64",
+                3
+              ]
+            }
+          },
+          {
+            "name": "totalSolWon",
+            "docs": [
+              "Lamports credited as winner shares. Counts credits, not payouts:",
+              "shares later returned to the pot by `sweep_expired_winnings` stay",
+              "included."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Referral",
+      "docs": [
+        "Per-player referral link, and the SNOW the player has earned as a",
+        "referrer of others."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "publicKey"
+          },
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "referrer",
+            "docs": [
+              "Set on the player's first push and never changed."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "earned",
+            "type": "u64"
+          },
+          {
+            "name": "totalEarned",
+            "type": "u64"
+          },
+          {
+            "name": "lastCreditedAt",
+            "docs": [
+              "Time of the last referral credit, for `sweep_expired_referral`."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlayerRound",
+      "docs": [
+        "A player's pushes in one round, used to pay the round's dividend."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "publicKey"
+          },
+          {
+            "name": "roundNumber",
+            "type": "u64"
+          },
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pushes",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RoundRecord",
+      "docs": [
+        "Immutable summary of a resolved round, written once by `resolve_round`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "publicKey"
+          },
+          {
+            "name": "roundNumber",
+            "type": "u64"
+          },
+          {
+            "name": "winners",
+            "type": {
+              "array": [
+                "publicKey",
+                3
+              ]
+            }
+          },
+          {
+            "name": "winnerPayouts",
+            "type": {
+              "array": [
+                "u64",
+                3
+              ]
+            }
+          },
+          {
+            "name": "creatorPayout",
+            "type": "u64"
+          },
+          {
+            "name": "keeper",
+            "type": "publicKey"
+          },
+          {
+            "name": "keeperBounty",
+            "type": "u64"
+          },
+          {
+            "name": "dividendPool",
+            "type": "u64"
+          },
+          {
+            "name": "dividendPerPush",
+            "type": "u64"
+          },
+          {
+            "name": "seed",
+            "docs": [
+              "Carryover of the previous round this round opened with."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "carryover",
+            "docs": [
+              "Set aside from this round's pot to seed the next one."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pushCount",
+            "type": "u64"
+          },
+          {
+            "name": "snowCollected",
+            "type": "u64"
+          },
+          {
+            "name": "snowWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "solDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "pot",
+            "type": "u64"
+          },
+          {
+            "name": "startedAt",
+            "type": "i64"
+          },
+          {
+            "name": "endedAt",
+            "type": "i64"
+          },
+          {
+            "name": "resolvedAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GameConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "game",
+            "type": "publicKey"
+          },
+          {
+            "name": "params",
+            "type": {
+              "defined": "GameParams"
+            }
+          },
+          {
+            "name": "pending",
+            "docs": [
+              "Staged by `update_config`, applied by the next `reset_game`."
+            ],
+            "type": {
+              "option": {
+                "defined": "GameParams"
+              }
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "GameParams",
+      "docs": [
+        "Tunable game rules. Shares are in basis points and index 0 of",
+        "`winner_shares_bps` is the last pusher. The keeper bounty is taken off",
+        "the top of the pot before the shares are applied."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pushCost",
+            "type": "u64"
+          },
+          {
+            "name": "roundDuration",
+            "type": "i64"
+          },
+          {
+            "name": "winnerSharesBps",
+            "type": {
+              "array": [
+                "u16",
+                3
+              ]
+            }
+          },
+          {
+            "name": "creatorShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "dividendShareBps",
+            "docs": [
+              "Slice of the pot split pro-rata across every push of the round."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "carryoverBps",
+            "docs": [
+              "Slice of the pot held back to seed the next round."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "keeperBountyBps",
+            "type": "u16"
+          },
+          {
+            "name": "claimExpiry",
+            "docs": [
+              "Seconds after which unclaimed winnings may be swept back to the pot."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxWithdrawPerRound",
+            "docs": [
+              "Limits on `withdraw_snow_for_swap`, in SNOW base units."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "withdrawWindow",
+            "type": "i64"
+          },
+          {
+            "name": "maxWithdrawPerWindow",
+            "type": "u64"
+          },
+          {
+            "name": "minVaultReserve",
+            "type": "u64"
+          },
+          {
+            "name": "priceCurve",
+            "type": {
+              "defined": "PriceCurve"
+            }
+          },
+          {
+            "name": "burnBps",
+            "docs": [
+              "Slice of each push burned instead of sent to the vault."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "referralBps",
+            "docs": [
+              "Slice of each referred push owed to the player's referrer."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "solPushPrice",
+            "docs": [
+              "Lamport price of `push_ball_sol`; zero disables SOL pushes."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "repeatWinnerSlots",
+            "docs": [
+              "Lets one wallet hold several winner slots, one per push, including",
+              "from a single `push_many`. Otherwise a push moves the pusher to the",
+              "last slot."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "timerExtension",
+            "docs": [
+              "Seconds a push adds to the remaining time, up to `timer_cap`.",
+              "Zero keeps the hard reset to `round_duration`."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "timerCap",
+            "type": "i64"
+          },
+          {
+            "name": "snipeWindow",
+            "docs": [
+              "A push with less than `snipe_window` seconds left leaves at least",
+              "`snipe_min_remaining` seconds on the clock."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "snipeMinRemaining",
+            "type": "i64"
+          },
+          {
+            "name": "maxRoundLength",
+            "docs": [
+              "Pushes are rejected this many seconds after the round opened;",
+              "zero means no limit."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapVenue",
+      "docs": [
+        "DEX used by `convert_snow` to sell SNOW for SOL."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "PumpFun"
+          },
+          {
+            "name": "RaydiumV4"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceCurve",
+      "docs": [
+        "How the SNOW price of a push grows with the round's `push_count`,",
+        "starting from `GameParams::push_cost`."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Fixed"
+          },
+          {
+            "name": "Linear",
+            "fields": [
+              {
+                "name": "increment",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "Exponential",
+            "fields": [
+              {
+                "name": "growthBps",
+                "type": "u16"
+              }
+            ]
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "PushEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pusher",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pushes",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pushCount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "burned",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "received",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "referralShare",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "snowCollected",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalBurned",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timerEndTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SolPushEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pusher",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pushCount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "potBalanceSol",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timerEndTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SolDepositEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "depositor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "potBalanceSol",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SnowWithdrawEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "manager",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "snowCollected",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "roundSnowWithdrawn",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RoundEndedEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pushCount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "winners",
+          "type": {
+            "array": [
+              "publicKey",
+              3
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "winnerPayouts",
+          "type": {
+            "array": [
+              "u64",
+              3
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creatorPayout",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "keeper",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "keeperBounty",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "dividendPool",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "carryover",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "rollover",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ConfigUpdateEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "params",
+          "type": {
+            "defined": "GameParams"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WinningsClaimEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DividendClaimEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pushes",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReferralClaimEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WinningsSweepEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "nextRoundSeed",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DividendSweepEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "nextRoundSeed",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReferralSweepEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PotSyncEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendable",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "surplus",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "potBalanceSol",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityProposeEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposed",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityTransferEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previous",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LiquidityManagerProposeEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liquidityManager",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposed",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "eta",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LiquidityManagerTransferEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previous",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liquidityManager",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TreasuryProposeEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposed",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "eta",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TreasuryTransferEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previous",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SnowConvertEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "venue",
+          "type": {
+            "defined": "SwapVenue"
+          },
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "snowIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "solOut",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "potBalanceSol",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GuardianUpdateEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PauseEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pausedBy",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UnpauseEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pausedFor",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "timerEndTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "StateMigrateEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LegacyDrainEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "legacy",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "snow",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "potBalanceSol",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GameCloseEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "snow",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "lamports",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GameResetEvent",
+      "fields": [
+        {
+          "name": "game",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "roundNumber",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timerEndTimestamp",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "seed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "potBalanceSol",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "GameNotActive",
+      "msg": "ErrActive"
+    },
+    {
+      "code": 6001,
+      "name": "RoundEnded",
+      "msg": "ErrEnded"
+    },
+    {
+      "code": 6002,
+      "name": "MathOverflow",
+      "msg": "ErrOvrflw"
+    },
+    {
+      "code": 6003,
+      "name": "RoundStillActive",
+      "msg": "ErrActive"
+    },
+    {
+      "code": 6004,
+      "name": "Unauthorized",
+      "msg": "ErrAuth"
+    },
+    {
+      "code": 6005,
+      "name": "WinnerMismatch",
+      "msg": "ErrWinner"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidConfig",
+      "msg": "ErrConfig"
+    },
+    {
+      "code": 6007,
+      "name": "NothingToClaim",
+      "msg": "ErrNoClaim"
+    },
+    {
+      "code": 6008,
+      "name": "ClaimNotExpired",
+      "msg": "ErrNotExpired"
+    },
+    {
+      "code": 6009,
+      "name": "PotMismatch",
+      "msg": "ErrPot"
+    },
+    {
+      "code": 6010,
+      "name": "TimelockActive",
+      "msg": "ErrTimelock"
+    },
+    {
+      "code": 6011,
+      "name": "WithdrawLimitExceeded",
+      "msg": "ErrWithdrawLimit"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidSwapAccounts",
+      "msg": "ErrSwapAccounts"
+    },
+    {
+      "code": 6013,
+      "name": "SlippageExceeded",
+      "msg": "ErrSlippage"
+    },
+    {
+      "code": 6014,
+      "name": "GamePaused",
+      "msg": "ErrPaused"
+    },
+    {
+      "code": 6015,
+      "name": "GameNotPaused",
+      "msg": "ErrNotPaused"
+    },
+    {
+      "code": 6016,
+      "name": "RoundLengthExceeded",
+      "msg": "ErrMaxLength"
+    },
+    {
+      "code": 6017,
+      "name": "ReferrerMismatch",
+      "msg": "ErrReferrer"
+    },
+    {
+      "code": 6018,
+      "name": "SolPushDisabled",
+      "msg": "ErrSolPush"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidPushCount",
+      "msg": "ErrPushCount"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidLayout",
+      "msg": "ErrLayout"
+    },
+    {
+      "code": 6021,
+      "name": "UnclaimedWinnings",
+      "msg": "ErrUnclaimed"
+    },
+    {
+      "code": 6022,
+      "name": "SeasonAlreadyPlayed",
+      "msg": "ErrSeasonUsed"
+    }
   ]
 };